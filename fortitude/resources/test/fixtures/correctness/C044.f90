@@ -0,0 +1,23 @@
+subroutine load(filename, x)
+  implicit none (type, external)
+  character(len=*), intent(in) :: filename
+  real, intent(out) :: x
+  integer :: unit, stat
+  character(len=100) :: message
+  logical :: exists
+
+  inquire(file=filename, exist=exists)
+  open(newunit=unit, file=filename, action="read", status="old")
+  read(unit, *) x
+  write(unit, '(a)') "done"
+  close(unit)
+
+  inquire(file=filename, exist=exists, iostat=stat)
+  open(newunit=unit, file=filename, action="read", status="old", IOSTAT=stat, iomsg=message)
+  read(unit, *, iostat=stat) x
+  read(unit, *, err=10) x
+  read *, x
+  write(*, *) x
+  close(unit, iostat=stat)
+10 continue
+end subroutine load
@@ -0,0 +1,59 @@
+subroutine load(filename, x)
+  implicit none (type, external)
+  character(len=*), intent(in) :: filename
+  real, intent(out) :: x
+  integer :: unit, stat
+
+  open(newunit=unit, file=filename, action="read", status="old", iostat=stat)
+  read(unit, *, iostat=stat) x
+  if (stat /= 0) return
+  close(unit, iostat=stat)
+end subroutine load
+
+subroutine save(filename, x)
+  use, intrinsic :: iso_fortran_env, only: error_unit
+  implicit none (type, external)
+  character(len=*), intent(in) :: filename
+  real, intent(in) :: x
+  integer :: unit, stat
+  character(len=100) :: message
+
+  open(newunit=unit, file=filename, action="write", status="replace", iostat=stat, iomsg=message)
+  if (stat /= 0) then
+    write(error_unit, '(a)') trim(message)
+    return
+  end if
+  write(unit, *, iostat=stat) x
+  stat = 0
+  close(unit, IOSTAT=stat)
+  call check(STAT)
+contains
+  subroutine check(status)
+    integer, intent(in) :: status
+    if (status /= 0) error stop
+  end subroutine check
+end subroutine save
+
+subroutine sum_values(unit, total)
+  implicit none (type, external)
+  integer, intent(in) :: unit
+  real, intent(out) :: total
+  real :: x
+  integer :: stat
+
+  total = 0.0
+  read(unit, *, iostat=stat) x
+  do while (stat == 0)
+    total = total + x
+    read(unit, *, iostat=stat) x
+  end do
+end subroutine sum_values
+
+subroutine write_value(unit, x, stat)
+  implicit none (type, external)
+  integer, intent(in) :: unit
+  real, intent(in) :: x
+  integer, intent(out) :: stat
+
+  write(unit, *, iostat=stat) x
+end subroutine write_value
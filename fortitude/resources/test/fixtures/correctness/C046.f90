@@ -0,0 +1,13 @@
+program test
+  implicit none (type, external)
+  integer :: unit
+
+  open(newunit=unit, file="input.txt", action="read")
+  close(unit)
+  open(newunit=unit, file="input.txt", action="read", status="old")
+  close(unit)
+  open(newunit=unit, file="output.txt", action="write", STATUS="replace")
+  close(unit)
+  open(10, file="output.txt", action="write")
+  close(10)
+end program test
@@ -0,0 +1,14 @@
+program test
+  implicit none (type, external)
+  integer :: unit
+  integer, parameter :: log_unit = 20
+
+  open(10, file="input.txt", action="read", status="old")
+  close(10)
+  open(unit=11, file="output.txt", action="write", status="replace")
+  close(11)
+  open(log_unit, file="log.txt", action="write", status="replace")
+  close(log_unit)
+  open(newunit=unit, file="input.txt", action="read", status="old")
+  close(unit)
+end program test
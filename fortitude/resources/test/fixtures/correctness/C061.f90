@@ -19,4 +19,22 @@ contains
     type(integer(kind=int64)), intent(inout) :: f
     integer :: g
   end subroutine bar
+
+  subroutine baz(x, y, z, w, n)
+    integer :: x, y, z
+    real :: w(n)
+    integer n
+    y = x
+    z = z + 1
+    w = 0.0
+  end subroutine baz
+
+  subroutine qux(flag, x, y)
+    logical, intent(in) :: flag
+    integer :: x, y
+    if (flag) then
+      x = 1
+    end if
+    if (flag) y = 2
+  end subroutine qux
 end module mod_test
@@ -0,0 +1,37 @@
+module test
+  implicit none
+contains
+  subroutine set(x)
+    integer, intent(out) :: x
+    x = 1
+  end subroutine set
+
+  subroutine bad(a, b, c, d, e)
+    integer, intent(in) :: a, b(:), c, d
+    integer, allocatable, intent(in) :: e(:)
+    a = 1
+    read (*, *) b(1)
+    call set(c)
+    call set(x=d)
+    deallocate(e)
+  end subroutine bad
+
+  subroutine good(a, b, c)
+    integer, intent(in) :: a, b, c
+    integer :: i
+    i = a
+    call unknown(b)
+    call set(i)
+    print *, c
+  end subroutine good
+
+  subroutine pointers(p, q, r, s)
+    integer, pointer, intent(in) :: p, q, r(:), s
+    integer, target :: t
+    p = 1
+    q => t
+    nullify(r)
+    allocate(s)
+    r(1) = 2
+  end subroutine pointers
+end module test
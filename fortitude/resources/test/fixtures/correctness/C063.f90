@@ -0,0 +1,38 @@
+module test
+  implicit none
+contains
+  subroutine set(x)
+    integer, intent(out) :: x
+    x = 1
+  end subroutine set
+
+  subroutine bad(a, b)
+    integer, intent(out) :: a, b(:)
+    print *, "never assigned"
+  end subroutine bad
+
+  subroutine good(a, b, c, d, e)
+    integer, intent(out) :: a, b, c, d
+    integer, allocatable, intent(out) :: e(:)
+    a = 1
+    read (*, *) b
+    call set(c)
+    allocate(e(10))
+    call inner()
+  contains
+    subroutine inner()
+      d = 4
+    end subroutine inner
+  end subroutine good
+
+  subroutine no_intent(x)
+    integer :: x
+    x = 2
+  end subroutine no_intent
+
+  subroutine unknown_procedures(a, b)
+    integer, intent(out) :: a, b
+    call unknown(a)
+    call no_intent(b)
+  end subroutine unknown_procedures
+end module test
@@ -0,0 +1,22 @@
+module solver
+  implicit none
+  real :: tolerance = 1.0e-6
+contains
+  logical function converged(x, y, n)
+    real(kind=8), intent(in) :: x
+    double precision, intent(in) :: y(:)
+    integer, intent(in) :: n
+    integer :: i
+    converged = .false.
+    if (x == 0.0) return
+    if (x .eq. y(1)) converged = .true.
+    if (abs(x - y(n)) < tolerance) converged = .true.
+    if (n == 3) converged = .true.
+    if (x /= x) converged = .false.
+    if (real(n) .ne. -1.5e3) converged = .false.
+    do i = 1, n
+      if (i == n) exit
+    end do
+    if (tolerance == 0) converged = .false.
+  end function converged
+end module solver
@@ -0,0 +1,8 @@
+subroutine normalise(x, scale)
+  real, intent(inout) :: x(:)
+  real, intent(in) :: scale
+  if (scale == 0.0) return
+  if (scale /= 0.0d0 .and. scale /= -0._real64) x = x / scale
+  if (scale == 1.0) return
+  if (sum(x) .eq. 0) x = 1.0
+end subroutine normalise
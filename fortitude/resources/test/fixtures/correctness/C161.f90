@@ -0,0 +1,21 @@
+module physics
+  use, intrinsic :: iso_fortran_env, only: dp => real64
+  implicit none
+contains
+  subroutine update(x, y, n)
+    real(dp), intent(inout) :: x
+    real, intent(in) :: y
+    integer, intent(in) :: n
+    integer :: half
+    double precision :: z
+    x = 1/3 * y
+    x = x + n/2
+    z = 2/n
+    x = x ** (1/2)
+    half = n/2
+    x = real(n, dp)/2
+    x = y * (n/2 + 1)
+    x = 1_int64/2
+    x = n/half
+  end subroutine update
+end module physics
@@ -0,0 +1,19 @@
+module dynamics
+  use, intrinsic :: iso_fortran_env, only: real32, real64
+  implicit none
+  integer, parameter :: wp = real64
+contains
+  subroutine step(x, y, v, dt, n)
+    real(real64), intent(inout) :: x
+    real(real32), intent(in) :: y
+    real(kind=real64), intent(in) :: v(:)
+    real(wp), intent(in) :: dt
+    integer, intent(in) :: n
+    x = x + 0.1 * v(1)
+    x = x * y
+    x = x + 0.5_real64 * v(n) / n
+    x = x + 1.0d0 * dt
+    x = (x + y) + v(2)
+    x = x ** 2 + 2 * x
+  end subroutine step
+end module dynamics
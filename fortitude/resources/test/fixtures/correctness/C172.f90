@@ -0,0 +1,14 @@
+subroutine store(a, b, c, d)
+  use, intrinsic :: iso_fortran_env, only: sp => real32, dp => real64
+  implicit none
+  real(sp), intent(out) :: a
+  real(dp), intent(in) :: b
+  real, intent(out) :: c
+  complex(kind=sp), intent(out) :: d
+  a = b
+  a = real(b, sp)
+  c = 2.0d0 * a
+  d = b
+  a = 1.0
+  c = a + b * 2
+end subroutine store
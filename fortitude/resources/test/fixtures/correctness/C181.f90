@@ -0,0 +1,28 @@
+module test
+  implicit none (type, external)
+contains
+  subroutine bad(n)
+    integer, intent(in) :: n
+    real, allocatable :: x(:), y(:)
+    allocate(x(n))
+    allocate(y(n), source=0.0)
+    deallocate(x, y)
+  end subroutine bad
+
+  subroutine good(n)
+    integer, intent(in) :: n
+    real, allocatable :: x(:)
+    integer :: status
+    character(len=100) :: message
+    allocate(x(n), stat=status, errmsg=message)
+    if (status /= 0) return
+    deallocate(x, STAT=status)
+  end subroutine good
+end module test
+
+program main
+  implicit none (type, external)
+  real, allocatable :: z(:)
+  allocate(z(10))
+  deallocate(z)
+end program main
@@ -0,0 +1,46 @@
+module test
+  implicit none (type, external)
+contains
+  subroutine bad(n)
+    integer, intent(in) :: n
+    real, allocatable :: x(:), y(:)
+    integer :: status
+    allocate(x(n), stat=status)
+    allocate(y(n), stat=status)
+    if (status /= 0) return
+    deallocate(x, y, stat=status)
+  end subroutine bad
+
+  subroutine overwritten(n)
+    integer, intent(in) :: n
+    real, allocatable :: x(:)
+    integer :: status
+    allocate(x(n), stat=status)
+    status = 0
+    print *, status
+  end subroutine overwritten
+
+  subroutine good(n)
+    integer, intent(in) :: n
+    real, allocatable :: x(:)
+    integer :: status
+    allocate(x(n), stat=status)
+    if (status /= 0) then
+      error stop "allocation failed"
+    end if
+    deallocate(x, stat=status)
+    call check(STATUS)
+  end subroutine good
+
+  subroutine check(status)
+    integer, intent(in) :: status
+    if (status /= 0) error stop
+  end subroutine check
+
+  subroutine checked_by_caller(n, status)
+    integer, intent(in) :: n
+    integer, intent(out) :: status
+    real, allocatable :: x(:)
+    allocate(x(n), stat=status)
+  end subroutine checked_by_caller
+end module test
@@ -14,3 +14,19 @@ function complex_mul(x, y)
   double complex :: complex_mul
   complex_mul = x * y
 end function complex_mul
+
+module kinds
+  use, intrinsic :: iso_fortran_env, only: int32
+  implicit none
+contains
+  subroutine quadruple(x, i)
+    double precision, intent(inout) :: x
+    integer(int32), intent(in) :: i
+    x = 4d0 * x + dble(i) * 1.5D-3 + dfloat(i + 1)
+  contains
+    subroutine halve(y)
+      real, intent(inout) :: y
+      y = 0.5d0 * y
+    end subroutine halve
+  end subroutine quadruple
+end module kinds
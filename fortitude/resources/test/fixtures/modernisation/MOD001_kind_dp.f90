@@ -0,0 +1,7 @@
+subroutine scale(x, y)
+  use kinds, only: sp
+  implicit none
+  double precision, intent(inout) :: x
+  double complex, intent(in) :: y
+  x = 2.0d0 * x + dble(y)
+end subroutine scale
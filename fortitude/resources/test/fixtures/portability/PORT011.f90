@@ -23,3 +23,9 @@ complex(real64) function complex_add(x, y)
   complex(kind=4), intent(in) :: y
   complex_add = y + x
 end function complex_add
+
+module kinds
+  use, intrinsic :: iso_fortran_env, only: int32
+  implicit none
+  integer(kind=8) :: big
+end module kinds
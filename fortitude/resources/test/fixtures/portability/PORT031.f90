@@ -0,0 +1,26 @@
+module physics
+  use, intrinsic :: iso_fortran_env, only: real64
+  use precision_mod, only: sp
+  implicit none
+  real(wp), parameter :: g = 9.81_wp
+  real(real64) :: mass
+  real(kind=dp) :: height
+  real*8 :: energy
+  complex(8) :: phase
+  double precision :: volume
+  double complex :: wave
+  real :: speed
+  integer(8) :: count
+contains
+  subroutine fall(t)
+    real(WP), intent(in) :: t
+    height = 0.5_dp * g * t**2
+    speed = 1.0e3_real64 + 2_int64
+    volume = 2.5d-1 * t
+  end subroutine fall
+end module physics
+
+program main
+  real(8) :: x
+  x = 1.0_sp
+end program main
@@ -1,5 +1,5 @@
 use crate::ast::FortitudeNode;
-use crate::registry::AsRule;
+use crate::custom_rules::CustomRule;
 use crate::rule_redirects::get_redirect_target;
 use crate::rule_table::RuleTable;
 use crate::rules::fortitude::allow_comments::{
@@ -17,12 +17,41 @@ use rustc_hash::FxHashSet;
 use std::str::FromStr;
 use tree_sitter::Node;
 
+/// A rule that can be named in an allow comment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AllowedRule {
+    Builtin(Rule),
+    /// A custom rule, identified by its name
+    Custom(String),
+}
+
+impl AllowedRule {
+    /// Returns true if this is the rule that raised `diagnostic`
+    fn raised(&self, diagnostic: &Diagnostic) -> bool {
+        match self {
+            AllowedRule::Builtin(rule) => {
+                Rule::from_violation_name(&diagnostic.kind.name) == Some(*rule)
+            }
+            AllowedRule::Custom(name) => *name == diagnostic.kind.name,
+        }
+    }
+
+    fn enabled(&self, rules: &RuleTable, custom_rules: &[CustomRule]) -> bool {
+        match self {
+            AllowedRule::Builtin(rule) => rules.enabled(*rule),
+            AllowedRule::Custom(name) => custom_rules
+                .iter()
+                .any(|custom_rule| custom_rule.name == *name && custom_rule.enabled),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct Code<'a> {
     // The original rule/code/category in the comment
     pub code: &'a str,
     // Resolved rule
-    pub rule: Option<Rule>,
+    pub rule: Option<AllowedRule>,
     // The location of the code
     pub loc: TextRange,
 }
@@ -42,6 +71,7 @@ pub struct AllowComment<'a, 'b> {
 pub fn gather_allow_comments<'a, 'b>(
     node: &Node<'b>,
     file: &'a SourceFile,
+    custom_rules: &[CustomRule],
 ) -> Option<AllowComment<'a, 'b>> {
     if node.kind() != "comment" {
         return None;
@@ -83,8 +113,11 @@ pub fn gather_allow_comments<'a, 'b>(
         let code = rule.as_str();
         let redirect = get_redirect_target(code).unwrap_or(code);
         let rule = match Rule::from_code(redirect).or(Rule::from_str(redirect)) {
-            Ok(rule) => Some(rule),
-            Err(_) => None,
+            Ok(rule) => Some(AllowedRule::Builtin(rule)),
+            Err(_) => custom_rules
+                .iter()
+                .find(|custom_rule| custom_rule.code == code || custom_rule.name == code)
+                .map(|custom_rule| AllowedRule::Custom(custom_rule.name.clone())),
         };
 
         codes.push(Code { code, rule, loc });
@@ -102,6 +135,7 @@ pub fn check_allow_comments(
     diagnostics: &mut Vec<Diagnostic>,
     allow_comments: &[AllowComment],
     rules: &RuleTable,
+    custom_rules: &[CustomRule],
     file: &SourceFile,
 ) -> Vec<usize> {
     // Indices of diagnostics that were ignored by a `noqa` directive.
@@ -113,10 +147,8 @@ pub fn check_allow_comments(
     'outer: for (index, diagnostic) in diagnostics.iter().enumerate() {
         for allow in allow_comments {
             for code in &allow.codes {
-                if let Some(rule) = code.rule {
-                    if rule == diagnostic.kind.rule()
-                        && allow.range.contains_range(diagnostic.range)
-                    {
+                if let Some(rule) = &code.rule {
+                    if rule.raised(diagnostic) && allow.range.contains_range(diagnostic.range) {
                         used_codes.insert(rule);
                         ignored_diagnostics.push(index);
                         // We've ignored this diagnostic, so no point
@@ -155,7 +187,7 @@ pub fn check_allow_comments(
             let rule_str = code.code.to_string();
             let edit = remove_code_from_allow_comment(comment, code, file);
 
-            match code.rule {
+            match &code.rule {
                 None => {
                     if rules.enabled(Rule::InvalidRuleCodeOrName) {
                         diagnostics.push(
@@ -165,8 +197,8 @@ pub fn check_allow_comments(
                     }
                 }
                 Some(rule) => {
-                    let used = used_codes.contains(&rule);
-                    let enabled = rules.enabled(rule);
+                    let used = used_codes.contains(rule);
+                    let enabled = rule.enabled(rules, custom_rules);
                     if !seen_codes.insert(rule) && rules.enabled(Rule::DuplicatedAllowComment) {
                         diagnostics.push(
                             Diagnostic::new(DuplicatedAllowComment { rule: rule_str }, code.loc)
@@ -0,0 +1,272 @@
+//! A library API for embedding Fortitude in other tools, without going
+//! through the command line.
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! use fortitude::{lint_source, SettingsBuilder};
+//!
+//! let settings = SettingsBuilder::new()
+//!     .select(["C001".parse()?, "style".parse()?])
+//!     .line_length(120)
+//!     .build()?;
+//!
+//! let source = "program test\nend program test\n";
+//! for message in lint_source(source, Path::new("test.f90"), &settings)? {
+//!     let location = message.compute_start_location();
+//!     println!(
+//!         "{}:{}: {} {}",
+//!         location.row,
+//!         location.column,
+//!         message.name(),
+//!         message.body()
+//!     );
+//! }
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use ruff_source_file::SourceFileBuilder;
+
+use crate::check::{
+    self, ast_entrypoint_map, check_and_fix_file, check_file, check_files, remove_per_file_ignores,
+    rules_to_path_rules, rules_to_text_rules, withhold_fixes, FixSyntaxError, FixTable,
+    FixerResult,
+};
+use crate::cli::{CheckArgs, GlobalConfigArgs};
+use crate::configuration::{load_options, Configuration};
+use crate::fs::get_files;
+use crate::message::DiagnosticMessage;
+use crate::options::{CheckOptions, Options};
+use crate::rule_selector::RuleSelector;
+use crate::settings::{FixMode, ProgressBar, Settings};
+
+/// Builds [`Settings`] for [`lint_source`] and [`fix_source`], applying the
+/// same defaults and rule selection logic as the command line.
+#[derive(Debug, Default, Clone)]
+pub struct SettingsBuilder {
+    options: CheckOptions,
+    project_root: Option<PathBuf>,
+}
+
+impl SettingsBuilder {
+    /// Start from the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start from the settings in a `fortitude.toml` or `fpm.toml` file. The
+    /// directory containing the file is used as the project root.
+    pub fn from_config_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let options = load_options(path)
+            .with_context(|| format!("Failed to load config file {}", path.display()))?;
+        Ok(Self {
+            options: options.check.unwrap_or_default(),
+            project_root: path.parent().map(Path::to_path_buf),
+        })
+    }
+
+    /// Set the directory that relative paths in `exclude` and
+    /// `per-file-ignores` are resolved against. Defaults to the current
+    /// working directory.
+    #[must_use]
+    pub fn project_root<P: AsRef<Path>>(mut self, project_root: P) -> Self {
+        self.project_root = Some(project_root.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set the rules to check, replacing the defaults.
+    #[must_use]
+    pub fn select(mut self, selectors: impl IntoIterator<Item = RuleSelector>) -> Self {
+        self.options.select = Some(selectors.into_iter().collect());
+        self
+    }
+
+    /// Check these rules in addition to those selected.
+    #[must_use]
+    pub fn extend_select(mut self, selectors: impl IntoIterator<Item = RuleSelector>) -> Self {
+        self.options.extend_select = Some(selectors.into_iter().collect());
+        self
+    }
+
+    /// Don't check these rules.
+    #[must_use]
+    pub fn ignore(mut self, selectors: impl IntoIterator<Item = RuleSelector>) -> Self {
+        self.options.ignore = Some(selectors.into_iter().collect());
+        self
+    }
+
+    /// Enable or disable preview rules and fixes.
+    #[must_use]
+    pub fn preview(mut self, preview: bool) -> Self {
+        self.options.preview = Some(preview);
+        self
+    }
+
+    /// Include unsafe fixes when fixing with [`fix_source`].
+    #[must_use]
+    pub fn unsafe_fixes(mut self, unsafe_fixes: bool) -> Self {
+        self.options.unsafe_fixes = Some(unsafe_fixes);
+        self
+    }
+
+    /// Set the maximum allowed line length.
+    #[must_use]
+    pub fn line_length(mut self, line_length: usize) -> Self {
+        self.options.line_length = Some(line_length);
+        self
+    }
+
+    /// Ignore the given rules in files matching `pattern`.
+    #[must_use]
+    pub fn per_file_ignores(
+        mut self,
+        pattern: impl Into<String>,
+        selectors: impl IntoIterator<Item = RuleSelector>,
+    ) -> Self {
+        self.options
+            .per_file_ignores
+            .get_or_insert_with(Default::default)
+            .insert(pattern.into(), selectors.into_iter().collect());
+        self
+    }
+
+    /// Resolve the final [`Settings`]. Fails if the rule selection is invalid.
+    pub fn build(self) -> Result<Settings> {
+        let project_root = match self.project_root {
+            Some(project_root) => project_root,
+            None => path_absolutize::path_dedot::CWD.to_path_buf(),
+        };
+        let options = Options {
+            check: Some(self.options),
+        };
+        Configuration::from_options(options, &project_root)
+            .into_settings(&project_root, &CheckArgs::default())
+    }
+}
+
+/// Resolve [`Settings`] for checking `files` in the same way as `fortitude
+/// check`. Unless `config_file` is given, the config file is discovered from
+/// the current working directory, exactly as on the command line.
+pub fn resolve_settings<P: AsRef<Path>>(
+    files: &[P],
+    config_file: Option<&Path>,
+) -> Result<Settings> {
+    let args = CheckArgs {
+        files: Some(
+            files
+                .iter()
+                .map(|file| file.as_ref().to_path_buf())
+                .collect(),
+        ),
+        progress_bar: Some(ProgressBar::Off),
+        ..CheckArgs::default()
+    };
+    let global_options = GlobalConfigArgs {
+        config_file: config_file.map(Path::to_path_buf),
+        ..GlobalConfigArgs::default()
+    };
+    let (settings, _) = check::resolve_settings(&args, &global_options)?;
+    Ok(settings)
+}
+
+/// Check all the files selected by `settings`, returning the violations
+/// found, sorted by file and location. Directories are searched for Fortran
+/// files, and `exclude` is respected, as on the command line.
+pub fn lint_paths(settings: &Settings) -> Result<Vec<DiagnosticMessage>> {
+    let rules = &settings.check.rules;
+    let files = get_files(&settings.file_resolver, false)?;
+
+    let mut results = check_files(
+        &files,
+        rules,
+        &rules_to_path_rules(rules),
+        &rules_to_text_rules(rules),
+        &ast_entrypoint_map(rules)?,
+        settings,
+        FixMode::Generate,
+        settings.check.ignore_allow_comments,
+    )?;
+    results.sort();
+    Ok(results.diagnostics.messages)
+}
+
+/// Check Fortran source code, returning the violations found, sorted by
+/// location. `path` is used in the messages, and for rules and settings that
+/// depend on the file name; it doesn't need to exist.
+pub fn lint_source(
+    source: &str,
+    path: &Path,
+    settings: &Settings,
+) -> Result<Vec<DiagnosticMessage>> {
+    let rules = &settings.check.rules;
+    let file = SourceFileBuilder::new(path.to_string_lossy().as_ref(), source).finish();
+
+    let mut diagnostics = check_file(
+        rules,
+        &rules_to_path_rules(rules),
+        &rules_to_text_rules(rules),
+        &ast_entrypoint_map(rules)?,
+        path,
+        &file,
+        settings,
+        FixMode::Generate,
+        settings.check.ignore_allow_comments,
+    )?;
+    diagnostics.messages.sort();
+    Ok(diagnostics.messages)
+}
+
+/// The result of [`fix_source`].
+#[derive(Debug)]
+pub struct FixedSource {
+    /// The source code with all applicable fixes applied.
+    pub source: String,
+    /// The number of fixes applied for each rule.
+    pub fixed: FixTable,
+    /// The violations remaining after fixing, sorted by location.
+    pub messages: Vec<DiagnosticMessage>,
+}
+
+/// Apply all available fixes to Fortran source code. Unsafe fixes are only
+/// applied if enabled in `settings`. If the source contains syntax errors, or
+/// a fix would introduce one, it is returned unchanged along with the
+/// remaining violations.
+pub fn fix_source(source: &str, path: &Path, settings: &Settings) -> Result<FixedSource> {
+    let rules = &settings.check.rules;
+    let file = SourceFileBuilder::new(path.to_string_lossy().as_ref(), source).finish();
+
+    match check_and_fix_file(
+        rules,
+        &rules_to_path_rules(rules),
+        &rules_to_text_rules(rules),
+        &ast_entrypoint_map(rules)?,
+        path,
+        &file,
+        settings,
+        settings.check.ignore_allow_comments,
+    ) {
+        Ok(FixerResult {
+            mut result,
+            transformed,
+            fixed,
+        }) => {
+            remove_per_file_ignores(&mut result, path, settings);
+            withhold_fixes(&mut result, rules);
+            result.sort();
+            Ok(FixedSource {
+                source: transformed.source_text().to_string(),
+                fixed,
+                messages: result,
+            })
+        }
+        Err(err) if err.is::<FixSyntaxError>() => Ok(FixedSource {
+            source: source.to_string(),
+            fixed: FixTable::default(),
+            messages: lint_source(source, path, settings)?,
+        }),
+        Err(err) => Err(err),
+    }
+}
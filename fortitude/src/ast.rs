@@ -30,7 +30,7 @@ impl<'a> Iterator for DepthFirstIterator<'a> {
 
 pub struct DepthFirstIteratorExcept<'a> {
     cursor: TreeCursor<'a>,
-    exceptions: Vec<String>, // TODO Use kind ids instead
+    exceptions: Vec<u16>,
 }
 
 impl<'a> Iterator for DepthFirstIteratorExcept<'a> {
@@ -38,10 +38,7 @@ impl<'a> Iterator for DepthFirstIteratorExcept<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         // ignore exception list if we're at a depth of 0
-        if (self.cursor.depth() == 0
-            || !self
-                .exceptions
-                .contains(&self.cursor.node().kind().to_string()))
+        if (self.cursor.depth() == 0 || !self.exceptions.contains(&self.cursor.node().kind_id()))
             && self.cursor.goto_first_child()
         {
             return Some(self.cursor.node());
@@ -129,9 +126,20 @@ impl FortitudeNode<'_> for Node<'_> {
     where
         I: IntoIterator<Item = &'tree str>,
     {
+        let language = self.language();
+        let exceptions = exceptions
+            .into_iter()
+            .flat_map(|kind| {
+                [
+                    language.id_for_node_kind(kind, true),
+                    language.id_for_node_kind(kind, false),
+                ]
+            })
+            .filter(|&kind_id| kind_id != 0)
+            .collect();
         DepthFirstIteratorExcept {
             cursor: self.walk(),
-            exceptions: exceptions.into_iter().map(|x| x.to_string()).collect(),
+            exceptions,
         }
     }
 
@@ -1,11 +1,17 @@
 use crate::allow_comments::{check_allow_comments, gather_allow_comments};
 use crate::ast::FortitudeNode;
 use crate::cli::{CheckArgs, GlobalConfigArgs};
-use crate::configuration::{self, parse_config_file, Configuration};
+use crate::configuration::{
+    self, apply_config_overrides, config_file_path, load_options, Configuration,
+};
+use crate::custom_rules::{self, check_custom_rules};
 use crate::diagnostics::{Diagnostics, FixMap};
 use crate::fix::{fix_file, FixResult};
 use crate::fs::get_files;
+use crate::interactive::review_fixes;
+use crate::logging::LogLevel;
 use crate::message::DiagnosticMessage;
+use crate::options::Options;
 use crate::printer::{Flags as PrinterFlags, Printer};
 use crate::registry::AsRule;
 use crate::rule_table::RuleTable;
@@ -17,10 +23,12 @@ use crate::settings::{self, CheckSettings, FixMode, ProgressBar, Settings};
 use crate::show_files::show_files;
 use crate::show_settings::show_settings;
 use crate::stdin::read_from_stdin;
+use crate::timing::{self, Stage, Timer};
 use crate::warn_user_once_by_message;
+use crate::watch::watch;
 use crate::{fs, locator::Locator, warn_user_once};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use indicatif::{ParallelProgressIterator, ProgressStyle};
 use itertools::Itertools;
@@ -31,7 +39,6 @@ use ruff_source_file::{SourceFile, SourceFileBuilder};
 use ruff_text_size::TextRange;
 use rustc_hash::FxHashMap;
 use std::borrow::Cow;
-use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Write;
 use std::io::{self, BufWriter};
@@ -39,10 +46,10 @@ use std::iter::once;
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use std::time::Instant;
-use tree_sitter::{Parser, Tree};
+use tree_sitter::{Language, Parser, Tree};
 
 /// Returns true if the command should read from standard input.
-fn is_stdin(files: &[PathBuf], stdin_filename: Option<&Path>) -> bool {
+pub(crate) fn is_stdin(files: &[PathBuf], stdin_filename: Option<&Path>) -> bool {
     // If the user provided a `--stdin-filename`, always read from standard input.
     if stdin_filename.is_some() {
         if let Some(file) = files.iter().find(|file| file.as_path() != Path::new("-")) {
@@ -67,7 +74,7 @@ pub(crate) fn check_file(
     rules: &RuleTable,
     path_rules: &Vec<PathRuleEnum>,
     text_rules: &Vec<TextRuleEnum>,
-    ast_entrypoints: &BTreeMap<&str, Vec<AstRuleEnum>>,
+    ast_entrypoints: &AstEntrypoints,
     path: &Path,
     file: &SourceFile,
     settings: &Settings,
@@ -132,28 +139,52 @@ pub(crate) fn check_file(
         (result, fixed)
     };
 
-    // Ignore based on per-file-ignores.
-    // If the DiagnosticMessage is discarded, its fix will also be ignored.
+    remove_per_file_ignores(&mut messages, path, settings);
+
+    let fixes_withheld = withhold_fixes(&mut messages, rules);
+
+    Ok(Diagnostics {
+        messages,
+        fixed: FixMap::from_iter([(fs::relativize_path(path), fixed)]),
+        fixes_withheld,
+    })
+}
+
+/// Discard any violations of rules ignored for this file by the
+/// `per-file-ignores` setting. Their fixes are discarded too.
+pub(crate) fn remove_per_file_ignores(
+    messages: &mut Vec<DiagnosticMessage>,
+    path: &Path,
+    settings: &Settings,
+) {
     let per_file_ignores = &settings.check.per_file_ignores;
-    let per_file_ignores = if !messages.is_empty() && !per_file_ignores.is_empty() {
-        fs::ignores_from_path(path, per_file_ignores)
-    } else {
-        vec![]
-    };
-    if !per_file_ignores.is_empty() {
+    if messages.is_empty() || per_file_ignores.is_empty() {
+        return;
+    }
+    let custom_ignores = fs::custom_ignores_from_path(path, per_file_ignores);
+    let per_file_ignores = fs::ignores_from_path(path, per_file_ignores);
+    if !per_file_ignores.is_empty() || !custom_ignores.is_empty() {
         messages.retain(|message| {
             if let Some(rule) = message.rule() {
                 !per_file_ignores.contains(&rule)
             } else {
-                true
+                !custom_ignores.contains(&message.code())
             }
         });
     }
+}
 
-    Ok(Diagnostics {
-        messages,
-        fixed: FixMap::from_iter([(fs::relativize_path(path), fixed)]),
-    })
+/// Remove fixes for any rules that have been marked as unfixable, returning
+/// the number of fixes removed
+pub(crate) fn withhold_fixes(messages: &mut [DiagnosticMessage], rules: &RuleTable) -> usize {
+    let mut fixes_withheld = 0;
+    for message in messages.iter_mut() {
+        if message.fixable() && message.rule().is_some_and(|rule| !rules.should_fix(rule)) {
+            message.remove_fix();
+            fixes_withheld += 1;
+        }
+    }
+    fixes_withheld
 }
 
 /// Parse a file, check it for issues, and return the report.
@@ -162,7 +193,7 @@ pub(crate) fn check_only_file(
     rules: &RuleTable,
     path_rules: &Vec<PathRuleEnum>,
     text_rules: &Vec<TextRuleEnum>,
-    ast_entrypoints: &BTreeMap<&str, Vec<AstRuleEnum>>,
+    ast_entrypoints: &AstEntrypoints,
     path: &Path,
     file: &SourceFile,
     settings: &Settings,
@@ -172,8 +203,8 @@ pub(crate) fn check_only_file(
     parser
         .set_language(&tree_sitter_fortran::LANGUAGE.into())
         .context("Error loading Fortran grammar")?;
-    let tree = parser
-        .parse(file.source_text(), None)
+    let tree = Timer::new()
+        .time(Stage::Parse, || parser.parse(file.source_text(), None))
         .context("Failed to parse")?;
 
     let violations = check_path(
@@ -190,10 +221,19 @@ pub(crate) fn check_only_file(
 
     Ok(violations
         .into_iter()
-        .map(|v| DiagnosticMessage::from_ruff(file, v))
+        .map(|v| to_message(file, v, settings))
         .collect_vec())
 }
 
+/// Convert a violation into a message, looking up the code if it was raised
+/// by a custom rule.
+fn to_message(file: &SourceFile, diagnostic: Diagnostic, settings: &Settings) -> DiagnosticMessage {
+    match custom_rules::code_for_name(&settings.check.custom_rules, &diagnostic.kind.name) {
+        Some(code) => DiagnosticMessage::from_custom_rule(file, diagnostic, code),
+        None => DiagnosticMessage::from_ruff(file, diagnostic),
+    }
+}
+
 /// Check an already parsed file. This actually does all the checking,
 /// `check_only_file`/`check_and_fix_file` wrap this
 #[allow(clippy::too_many_arguments)]
@@ -201,7 +241,7 @@ pub(crate) fn check_path(
     rules: &RuleTable,
     path_rules: &Vec<PathRuleEnum>,
     text_rules: &Vec<TextRuleEnum>,
-    ast_entrypoints: &BTreeMap<&str, Vec<AstRuleEnum>>,
+    ast_entrypoints: &AstEntrypoints,
     path: &Path,
     file: &SourceFile,
     settings: &Settings,
@@ -210,36 +250,42 @@ pub(crate) fn check_path(
 ) -> Vec<Diagnostic> {
     let mut violations = Vec::new();
     let mut allow_comments = Vec::new();
+    let mut timer = Timer::new();
 
     // Check file paths directly
     for rule in path_rules {
-        if let Some(violation) = rule.check(settings, path) {
+        if let Some(violation) = timer.time(Stage::Rule(rule.rule()), || rule.check(settings, path))
+        {
             violations.push(violation);
         }
     }
 
     // Perform plain text analysis
     for rule in text_rules {
-        violations.extend(rule.check(settings, file));
+        violations.extend(timer.time(Stage::Rule(rule.rule()), || rule.check(settings, file)));
     }
 
     // Perform AST analysis
     let root = tree.root_node();
     for node in once(root).chain(root.descendants()) {
-        if let Some(rules) = ast_entrypoints.get(node.kind()) {
-            for rule in rules {
-                if let Some(violation) = rule.check(settings, &node, file) {
-                    for v in violation {
-                        violations.push(v);
-                    }
+        for rule in ast_entrypoints.get(node.kind_id()) {
+            if let Some(violation) = timer.time(Stage::Rule(rule.rule()), || {
+                rule.check(settings, &node, file)
+            }) {
+                for v in violation {
+                    violations.push(v);
                 }
             }
         }
-        if let Some(allow_rules) = gather_allow_comments(&node, file) {
+        if let Some(allow_rules) = gather_allow_comments(&node, file, &settings.check.custom_rules)
+        {
             allow_comments.push(allow_rules);
         };
     }
 
+    // Check user-defined rules
+    violations.extend(check_custom_rules(&settings.check.custom_rules, tree, file));
+
     // Raise violations for internal test rules
     #[cfg(any(feature = "test-rules", test))]
     {
@@ -281,7 +327,13 @@ pub(crate) fn check_path(
             Rule::DisabledAllowComment,
         ])
     {
-        let ignored = check_allow_comments(&mut violations, &allow_comments, rules, file);
+        let ignored = check_allow_comments(
+            &mut violations,
+            &allow_comments,
+            rules,
+            &settings.check.custom_rules,
+            file,
+        );
         if ignore_allow_comments.is_disabled() {
             for index in ignored.iter().rev() {
                 violations.swap_remove(*index);
@@ -299,16 +351,20 @@ pub(crate) fn check_path(
         // Sort by byte-offset in the file
         violations.sort_by_key(|diagnostic| diagnostic.range.start());
         // Retain all violations up to the first syntax error, inclusive.
-        // Text and path rules can be safely retained.
-        let syntax_error_idx = violations
-            .iter()
-            .position(|diagnostic| diagnostic.kind.rule() == Rule::SyntaxError);
+        // Text and path rules can be safely retained. Custom rules are
+        // treated as AST rules.
+        let syntax_error_idx = violations.iter().position(|diagnostic| {
+            Rule::from_violation_name(&diagnostic.kind.name) == Some(Rule::SyntaxError)
+        });
         if let Some(syntax_error_idx) = syntax_error_idx {
             violations = violations
                 .into_iter()
                 .enumerate()
                 .filter_map(|(idx, diagnostic)| {
-                    if idx <= syntax_error_idx || !diagnostic.kind.rule().is_ast_rule() {
+                    if idx <= syntax_error_idx
+                        || Rule::from_violation_name(&diagnostic.kind.name)
+                            .is_some_and(|rule| !rule.is_ast_rule())
+                    {
                         Some(diagnostic)
                     } else {
                         None
@@ -318,6 +374,17 @@ pub(crate) fn check_path(
         }
     }
 
+    // Apply any user overrides to the safety of fixes
+    for diagnostic in &mut violations {
+        if let Some(fix) = diagnostic.fix.take() {
+            let applicability = settings
+                .check
+                .fix_safety
+                .resolve_applicability(diagnostic.kind.rule(), fix.applicability());
+            diagnostic.set_fix(fix.with_applicability(applicability));
+        }
+    }
+
     violations
 }
 
@@ -334,12 +401,22 @@ pub struct FixerResult<'a> {
     pub fixed: FixTable,
 }
 
+/// Why [`check_and_fix_file`] gave up on fixing a file. Callers should fall
+/// back to linting the original source.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum FixSyntaxError {
+    #[error("File contains syntax errors, no fixes will be applied")]
+    InSource,
+    #[error("Fix introduced a syntax error")]
+    IntroducedByFix,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn check_and_fix_file<'a>(
     rules: &RuleTable,
     path_rules: &Vec<PathRuleEnum>,
     text_rules: &Vec<TextRuleEnum>,
-    ast_entrypoints: &BTreeMap<&str, Vec<AstRuleEnum>>,
+    ast_entrypoints: &AstEntrypoints,
     path: &Path,
     file: &'a SourceFile,
     settings: &Settings,
@@ -361,10 +438,14 @@ pub(crate) fn check_and_fix_file<'a>(
         .set_language(&tree_sitter_fortran::LANGUAGE.into())
         .context("Error loading Fortran grammar")?;
 
+    let mut timer = Timer::new();
+
     // Continuously fix until the source code stabilizes.
     loop {
-        let tree = parser
-            .parse(transformed.source_text(), None)
+        let tree = timer
+            .time(Stage::Parse, || {
+                parser.parse(transformed.source_text(), None)
+            })
             .context("Failed to parse")?;
 
         // Map row and column locations to byte slices (lazily).
@@ -389,13 +470,11 @@ pub(crate) fn check_and_fix_file<'a>(
                     "Syntax errors detected in file: {}. No fixes will be applied.",
                     path.to_string_lossy()
                 );
-                return Err(anyhow!(
-                    "File contains syntax errors, no fixes will be applied"
-                ));
+                return Err(FixSyntaxError::InSource.into());
             }
         } else if is_valid_syntax && tree.root_node().has_error() {
             report_fix_syntax_error(path, transformed.source_text(), fixed.keys().copied());
-            return Err(anyhow!("Fix introduced a syntax error"));
+            return Err(FixSyntaxError::IntroducedByFix.into());
         }
 
         // Apply fix
@@ -403,12 +482,15 @@ pub(crate) fn check_and_fix_file<'a>(
             code: fixed_contents,
             fixes: applied,
             ..
-        }) = fix_file(
-            &violations,
-            &locator,
-            settings.check.unsafe_fixes,
-            path.to_string_lossy().as_ref(),
-        ) {
+        }) = timer.time(Stage::Fix, || {
+            fix_file(
+                &violations,
+                &locator,
+                rules,
+                settings.check.unsafe_fixes,
+                path.to_string_lossy().as_ref(),
+            )
+        }) {
             if iterations < MAX_ITERATIONS {
                 // Count the number of fixed errors
                 for (rule, count) in applied {
@@ -429,7 +511,7 @@ pub(crate) fn check_and_fix_file<'a>(
         return Ok(FixerResult {
             result: violations
                 .into_iter()
-                .map(|v| DiagnosticMessage::from_ruff(&transformed, v))
+                .map(|v| to_message(&transformed, v, settings))
                 .collect_vec(),
             transformed,
             fixed,
@@ -448,7 +530,11 @@ fn collect_rule_codes(rules: impl IntoIterator<Item = Rule>) -> String {
 
 #[allow(clippy::print_stderr)]
 fn report_failed_to_converge_error(path: &Path, transformed: &str, diagnostics: &[Diagnostic]) {
-    let codes = collect_rule_codes(diagnostics.iter().map(|diagnostic| diagnostic.kind.rule()));
+    let codes = collect_rule_codes(
+        diagnostics
+            .iter()
+            .filter_map(|diagnostic| Rule::from_violation_name(&diagnostic.kind.name)),
+    );
     if cfg!(debug_assertions) {
         eprintln!(
             "{}{} Failed to converge after {} iterations in `{}` with rule codes {}:---\n{}\n---",
@@ -480,7 +566,11 @@ This indicates a bug in fortitude. If you could open an issue at:
 }
 
 #[allow(clippy::print_stderr)]
-fn report_fix_syntax_error(path: &Path, transformed: &str, rules: impl IntoIterator<Item = Rule>) {
+pub(crate) fn report_fix_syntax_error(
+    path: &Path,
+    transformed: &str,
+    rules: impl IntoIterator<Item = Rule>,
+) {
     // TODO: include syntax error
     let codes = collect_rule_codes(rules);
     if cfg!(debug_assertions) {
@@ -550,33 +640,78 @@ pub(crate) fn rules_to_text_rules(rules: &RuleTable) -> Vec<TextRuleEnum> {
         .collect_vec()
 }
 
-/// Create a mapping of AST entrypoints to lists of the rules and codes that operate on them.
-pub(crate) fn ast_entrypoint_map<'a>(rules: &RuleTable) -> BTreeMap<&'a str, Vec<AstRuleEnum>> {
-    let ast_rules: Vec<AstRuleEnum> = rules
+/// The AST rules to run on each kind of node, stored in a dense table indexed
+/// by the node's kind id so that dispatch doesn't need any string comparisons.
+pub(crate) struct AstEntrypoints {
+    /// One entry per node kind in the grammar, plus one extra for `ERROR`
+    /// nodes, which have the special kind id [`u16::MAX`].
+    table: Vec<Vec<AstRuleEnum>>,
+}
+
+impl AstEntrypoints {
+    fn index(&self, kind_id: u16) -> usize {
+        if kind_id == ERROR_KIND_ID {
+            self.table.len() - 1
+        } else {
+            kind_id as usize
+        }
+    }
+
+    /// Get the rules that should be run on nodes of the given kind.
+    pub(crate) fn get(&self, kind_id: u16) -> &[AstRuleEnum] {
+        self.table
+            .get(self.index(kind_id))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// The kind id tree-sitter gives to `ERROR` nodes.
+const ERROR_KIND_ID: u16 = u16::MAX;
+
+/// Create a table of the AST rules that operate on each kind of node.
+///
+/// Returns an error if any rule's entrypoints aren't node kinds in the
+/// Fortran grammar, as such a rule would otherwise silently never run.
+pub(crate) fn ast_entrypoint_map(rules: &RuleTable) -> anyhow::Result<AstEntrypoints> {
+    let language: Language = tree_sitter_fortran::LANGUAGE.into();
+    let mut entrypoints = AstEntrypoints {
+        table: vec![Vec::new(); language.node_kind_count() + 1],
+    };
+
+    let ast_rules = rules
         .iter_enabled()
-        .filter_map(|rule| match TryFrom::try_from(rule) {
-            Ok(ast) => Some(ast),
-            _ => None,
-        })
-        .collect();
+        .filter_map(|rule| AstRuleEnum::try_from(rule).ok());
 
-    let mut map: BTreeMap<&'a str, Vec<_>> = BTreeMap::new();
     for rule in ast_rules {
         for entrypoint in rule.entrypoints() {
-            match map.get_mut(entrypoint) {
-                Some(rule_vec) => {
-                    rule_vec.push(rule);
-                }
-                None => {
-                    map.insert(entrypoint, vec![rule]);
-                }
+            // The same name may refer to both a named node and an anonymous
+            // token, and a rule should be run on either
+            let kind_ids = [true, false]
+                .into_iter()
+                .map(|named| language.id_for_node_kind(entrypoint, named))
+                .filter(|&kind_id| kind_id != 0)
+                .unique()
+                .collect_vec();
+
+            if kind_ids.is_empty() {
+                bail!(
+                    "Rule {} ({}) has unknown entrypoint '{entrypoint}'",
+                    rule.rule().noqa_code(),
+                    rule.rule().as_ref(),
+                );
+            }
+
+            for kind_id in kind_ids {
+                let index = entrypoints.index(kind_id);
+                entrypoints.table[index].push(rule);
             }
         }
     }
-    map
+    Ok(entrypoints)
 }
 
 /// Helper object to store the results of all checks
+#[derive(Clone)]
 pub(crate) struct CheckResults {
     /// All diagnostics found in all files
     pub(crate) diagnostics: Diagnostics,
@@ -587,7 +722,7 @@ pub(crate) struct CheckResults {
 }
 
 impl CheckResults {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             diagnostics: Diagnostics::default(),
             files_checked: 0,
@@ -619,14 +754,14 @@ impl CheckResults {
         self
     }
 
-    fn merge(mut self, other: CheckResults) -> Self {
+    pub(crate) fn merge(mut self, other: CheckResults) -> Self {
         self.diagnostics += other.diagnostics;
         self.files_checked += other.files_checked;
         self.files_skipped += other.files_skipped;
         self
     }
 
-    fn sort(&mut self) {
+    pub(crate) fn sort(&mut self) {
         self.diagnostics.messages.par_sort_unstable();
     }
 }
@@ -643,18 +778,89 @@ enum CheckStatus {
     SkippedNoDiagnostic,
 }
 
-/// Check all files, report issues found, and return error code.
-pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitCode> {
-    // First we need to find and read any config file
-    let project_root = configuration::project_root(path_absolutize::path_dedot::CWD.as_path())?;
-    let file_configuration = Configuration::from_options(
-        parse_config_file(&global_options.config_file)?,
-        &project_root,
-    );
+/// Find and read any config file, and combine it with the command line
+/// arguments to get the settings to check with. Also returns the path of the
+/// config file used, if any.
+pub(crate) fn resolve_settings(
+    args: &CheckArgs,
+    global_options: &GlobalConfigArgs,
+) -> Result<(Settings, Option<PathBuf>)> {
+    // First we need to find and read any config file, unless we've been
+    // told to ignore them
+    let cwd = path_absolutize::path_dedot::CWD.as_path();
+    let (project_root, config_file) = if global_options.isolated {
+        (fs::normalize_path(cwd), None)
+    } else {
+        (
+            configuration::project_root(cwd)?,
+            config_file_path(&global_options.config_file)?,
+        )
+    };
+    let options = match &config_file {
+        Some(config_file) => load_options(config_file)?,
+        None => Options::default(),
+    };
+
+    // Overrides from `--config` take precedence over the config file,
+    // but not over other CLI options
+    let options = apply_config_overrides(options, &global_options.config_overrides)?;
+    let file_configuration = Configuration::from_options(options, &project_root);
 
     // Now, we can override settings from the config file with options
     // from the CLI
-    let settings = file_configuration.into_settings(&project_root, &args)?;
+    let settings = file_configuration.into_settings(&project_root, args)?;
+
+    Ok((settings, config_file))
+}
+
+/// Determine whether fixes should be generated or applied.
+pub(crate) fn fix_mode_for(settings: &CheckSettings) -> FixMode {
+    // Fix rules are as follows:
+    // - By default, generate all fixes, but don't apply them to the filesystem.
+    // - If `--fix` or `--fix-only` is set, apply applicable fixes to the filesystem (or
+    //   print them to stdout, if we're reading from stdin).
+    // - If `--diff` or `--fix-only` are set, don't print any violations (only applicable fixes)
+    // - By default, applicable fixes only include [`Applicablility::Automatic`], but if
+    //   `--unsafe-fixes` is set, then [`Applicablility::Suggested`] fixes are included.
+
+    if settings.fix || settings.fix_only {
+        FixMode::Apply
+    } else {
+        FixMode::Generate
+    }
+}
+
+/// Create the printer used to report violations.
+pub(crate) fn make_printer(
+    settings: &CheckSettings,
+    fix_mode: FixMode,
+    log_level: LogLevel,
+) -> Printer {
+    let mut printer_flags = PrinterFlags::empty();
+    if !settings.fix_only {
+        printer_flags |= PrinterFlags::SHOW_VIOLATIONS;
+    }
+    if settings.show_fixes {
+        printer_flags |= PrinterFlags::SHOW_FIX_SUMMARY;
+    }
+
+    Printer::new(
+        settings.output_format,
+        log_level,
+        printer_flags,
+        fix_mode,
+        settings.unsafe_fixes,
+        settings.markdown_max_length,
+    )
+}
+
+/// Check all files, report issues found, and return error code.
+pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitCode> {
+    if args.watch {
+        return watch(&args, global_options);
+    }
+
+    let (settings, config_file) = resolve_settings(&args, global_options)?;
 
     let stdin_filename = args.stdin_filename;
 
@@ -674,7 +880,12 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
     let is_stdin = is_stdin(&args.files.unwrap_or_default(), stdin_filename.as_deref());
 
     if args.show_settings {
-        show_settings(&settings, &mut writer)?;
+        show_settings(
+            &settings,
+            config_file.as_deref(),
+            &global_options.config_overrides,
+            &mut writer,
+        )?;
         return Ok(ExitCode::SUCCESS);
     }
 
@@ -684,43 +895,59 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
     }
 
     let CheckSettings {
-        fix,
         fix_only,
         ref rules,
-        unsafe_fixes,
-        show_fixes,
-        output_format,
         ignore_allow_comments,
         ..
     } = settings.check;
 
-    // Fix rules are as follows:
-    // - By default, generate all fixes, but don't apply them to the filesystem.
-    // - If `--fix` or `--fix-only` is set, apply applicable fixes to the filesystem (or
-    //   print them to stdout, if we're reading from stdin).
-    // - If `--diff` or `--fix-only` are set, don't print any violations (only applicable fixes)
-    // - By default, applicable fixes only include [`Applicablility::Automatic`], but if
-    //   `--unsafe-fixes` is set, then [`Applicablility::Suggested`] fixes are included.
-
-    let fix_mode = if fix || fix_only {
-        FixMode::Apply
-    } else {
-        FixMode::Generate
-    };
+    let fix_mode = fix_mode_for(&settings.check);
 
     // At this point, we've assembled all our settings, and we're
     // ready to check the project
 
     let path_rules = rules_to_path_rules(rules);
     let text_rules = rules_to_text_rules(rules);
-    let ast_entrypoints = ast_entrypoint_map(rules);
+    let ast_entrypoints = ast_entrypoint_map(rules)?;
+
+    if args.timing.is_some() {
+        timing::enable();
+    }
 
     let start = Instant::now();
 
     let files = get_files(&settings.file_resolver, is_stdin)?;
     debug!("Identified files to lint in: {:?}", start.elapsed());
 
-    let results = if is_stdin {
+    // When reviewing fixes interactively, apply the accepted fixes first and
+    // then check the files again to report what's left
+    let interactive_fixes = if args.interactive {
+        if is_stdin {
+            bail!("Cannot review fixes interactively when reading from standard input");
+        }
+        if !settings.check.fix {
+            bail!("Cannot review fixes interactively unless fixes are enabled with `--fix`");
+        }
+        Some(review_fixes(
+            &files,
+            rules,
+            &path_rules,
+            &text_rules,
+            &ast_entrypoints,
+            &settings,
+            &mut io::stdin().lock(),
+            &mut io::stdout(),
+        )?)
+    } else {
+        None
+    };
+    let check_fix_mode = if interactive_fixes.is_some() {
+        FixMode::Generate
+    } else {
+        fix_mode
+    };
+
+    let mut results = if is_stdin {
         check_stdin(
             stdin_filename.map(fs::normalize_path).as_deref(),
             rules,
@@ -739,10 +966,13 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
             &text_rules,
             &ast_entrypoints,
             &settings,
-            fix_mode,
+            check_fix_mode,
             ignore_allow_comments,
         )?
     };
+    if let Some(fixed) = interactive_fixes {
+        results.diagnostics.fixed = fixed;
+    }
 
     // Always try to print violations (though the printer itself may suppress output)
     // If we're writing fixes via stdin, the transformed source code goes to the writer
@@ -753,21 +983,7 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
         writer
     };
 
-    let mut printer_flags = PrinterFlags::empty();
-    if !fix_only {
-        printer_flags |= PrinterFlags::SHOW_VIOLATIONS;
-    }
-    if show_fixes {
-        printer_flags |= PrinterFlags::SHOW_FIX_SUMMARY;
-    }
-
-    let printer = Printer::new(
-        output_format,
-        global_options.log_level(),
-        printer_flags,
-        fix_mode,
-        unsafe_fixes,
-    );
+    let printer = make_printer(&settings.check, fix_mode, global_options.log_level());
 
     if args.statistics {
         printer.write_statistics(&results.diagnostics, &mut summary_writer)?;
@@ -775,6 +991,11 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
         printer.write_once(&results, &mut summary_writer)?;
     }
 
+    if let Some(format) = args.timing {
+        summary_writer.flush()?;
+        timing::write_timings(&mut io::stderr().lock(), format, start.elapsed())?;
+    }
+
     let diagnostics = results.diagnostics;
     if !args.exit_zero {
         if fix_only {
@@ -800,12 +1021,12 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
 }
 
 #[allow(clippy::too_many_arguments)]
-fn check_files(
+pub(crate) fn check_files(
     files: &[PathBuf],
     rules: &RuleTable,
     path_rules: &Vec<PathRuleEnum>,
     text_rules: &Vec<TextRuleEnum>,
-    ast_entrypoints: &BTreeMap<&str, Vec<AstRuleEnum>>,
+    ast_entrypoints: &AstEntrypoints,
     settings: &Settings,
     fix_mode: FixMode,
     ignore_allow_comments: settings::IgnoreAllowComments,
@@ -925,7 +1146,7 @@ fn check_stdin(
     rules: &RuleTable,
     path_rules: &Vec<PathRuleEnum>,
     text_rules: &Vec<TextRuleEnum>,
-    ast_entrypoints: &BTreeMap<&str, Vec<AstRuleEnum>>,
+    ast_entrypoints: &AstEntrypoints,
     settings: &Settings,
     fix_mode: FixMode,
     ignore_allow_comments: settings::IgnoreAllowComments,
@@ -994,27 +1215,101 @@ fn check_stdin(
         (result, fixed)
     };
 
-    let per_file_ignores = &settings.check.per_file_ignores;
-    // Ignore based on per-file-ignores.
-    // If the DiagnosticMessage is discarded, its fix will also be ignored.
-    let per_file_ignores = if !messages.is_empty() && !per_file_ignores.is_empty() {
-        fs::ignores_from_path(path, per_file_ignores)
-    } else {
-        vec![]
-    };
-    if !per_file_ignores.is_empty() {
-        messages.retain(|message| {
-            if let Some(rule) = message.rule() {
-                !per_file_ignores.contains(&rule)
-            } else {
-                true
-            }
-        });
-    }
+    remove_per_file_ignores(&mut messages, path, settings);
+
+    let fixes_withheld = withhold_fixes(&mut messages, rules);
 
     let diagnostics = Diagnostics {
         messages,
         fixed: FixMap::from_iter([(fs::relativize_path(path), fixed)]),
+        fixes_withheld,
     };
     Ok(CheckResults::from_stdin(diagnostics))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::hint::black_box;
+    use std::iter::once;
+    use std::path::Path;
+    use std::time::{Duration, Instant};
+
+    use strum::IntoEnumIterator;
+    use tree_sitter::{Node, Parser};
+
+    use crate::ast::FortitudeNode;
+    use crate::rule_table::RuleTable;
+    use crate::rules::{AstRuleEnum, Rule};
+
+    use super::ast_entrypoint_map;
+
+    #[test]
+    fn entrypoints_are_valid_node_kinds() -> anyhow::Result<()> {
+        let rules = RuleTable::from_iter(Rule::iter());
+        let entrypoints = ast_entrypoint_map(&rules)?;
+
+        // Rules on both the root node and on syntax errors must be reachable
+        let language: tree_sitter::Language = tree_sitter_fortran::LANGUAGE.into();
+        let root = language.id_for_node_kind("translation_unit", true);
+        assert!(!entrypoints.get(root).is_empty());
+        assert!(!entrypoints.get(u16::MAX).is_empty());
+        Ok(())
+    }
+
+    /// Compare looking up the rules for each node in the kind id table with
+    /// looking them up by kind name in a map, as was done before the table was
+    /// introduced. This is a benchmark rather than a test, so run it with:
+    ///
+    /// ```sh
+    /// cargo test --release -p fortitude ast_dispatch_benchmark -- --ignored --nocapture
+    /// ```
+    #[test]
+    #[ignore]
+    fn ast_dispatch_benchmark() -> anyhow::Result<()> {
+        const ITERATIONS: u32 = 200;
+
+        let rules = RuleTable::from_iter(Rule::iter());
+        let entrypoints = ast_entrypoint_map(&rules)?;
+        let mut by_name: BTreeMap<&str, Vec<AstRuleEnum>> = BTreeMap::new();
+        for rule in rules
+            .iter_enabled()
+            .filter_map(|rule| AstRuleEnum::try_from(rule).ok())
+        {
+            for entrypoint in rule.entrypoints() {
+                by_name.entry(entrypoint).or_default().push(rule);
+            }
+        }
+
+        // Every test fixture, as one large file
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/test/fixtures");
+        let mut source = String::new();
+        for entry in ignore::Walk::new(fixtures).flatten() {
+            if entry.path().extension().is_some_and(|ext| ext == "f90") {
+                source.push_str(&std::fs::read_to_string(entry.path())?);
+                source.push('\n');
+            }
+        }
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_fortran::LANGUAGE.into())?;
+        let tree = parser.parse(&source, None).expect("fixtures should parse");
+        let root = tree.root_node();
+        let nodes: Vec<Node> = once(root).chain(root.descendants()).collect();
+
+        let time = |lookup: &dyn Fn(&Node) -> usize| -> Duration {
+            let start = Instant::now();
+            for _ in 0..ITERATIONS {
+                black_box(nodes.iter().map(lookup).sum::<usize>());
+            }
+            start.elapsed() / ITERATIONS
+        };
+        let table_time = time(&|node| entrypoints.get(node.kind_id()).len());
+        let map_time = time(&|node| by_name.get(node.kind()).map_or(0, Vec::len));
+
+        println!(
+            "{} nodes, per pass: kind id table {table_time:?}, kind name map {map_time:?}",
+            nodes.len()
+        );
+        Ok(())
+    }
+}
@@ -1,14 +1,17 @@
 use clap::{ArgAction::SetTrue, Parser, Subcommand};
 use serde::Deserialize;
+use std::fmt;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use crate::{
     build,
     fs::FilePattern,
     logging::LogLevel,
+    options::Options,
     rule_selector::RuleSelector,
     settings::{OutputFormat, PatternPrefixPair, ProgressBar},
-    RuleSelectorParser,
+    BuiltinRuleSelectorParser, RuleSelectorParser,
 };
 
 #[derive(Debug, Parser)]
@@ -35,8 +38,19 @@ pub struct GlobalConfigArgs {
     log_level_args: LogLevelArgs,
 
     /// Path to a TOML configuration file
-    #[arg(long)]
+    #[arg(long, conflicts_with = "isolated")]
     pub config_file: Option<PathBuf>,
+
+    /// Override a configuration option using an inline TOML `KEY = VALUE` pair,
+    /// such as `--config 'check.line-length = 132'`. Can be passed multiple times.
+    /// Overrides take precedence over the configuration file, but not over other
+    /// command-line options.
+    #[arg(long = "config", value_name = "KEY = VALUE")]
+    pub config_overrides: Vec<ConfigOverride>,
+
+    /// Ignore all configuration files.
+    #[arg(long)]
+    pub isolated: bool,
 }
 
 impl GlobalConfigArgs {
@@ -45,6 +59,48 @@ impl GlobalConfigArgs {
     }
 }
 
+/// A single configuration option given on the command line as an inline TOML
+/// `KEY = VALUE` pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigOverride {
+    raw: String,
+    table: toml::Table,
+}
+
+impl ConfigOverride {
+    /// The TOML table described by this override.
+    pub fn table(&self) -> &toml::Table {
+        &self.table
+    }
+}
+
+impl FromStr for ConfigOverride {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let table: toml::Table = toml::from_str(value).map_err(|err| {
+            format!("Failed to parse `{value}` as a TOML `KEY = VALUE` pair:\n{err}")
+        })?;
+
+        // Check this is a valid option before we try to merge it with
+        // anything else
+        toml::Value::Table(table.clone())
+            .try_into::<Options>()
+            .map_err(|err| format!("`{value}` is not a valid configuration option:\n{err}"))?;
+
+        Ok(Self {
+            raw: value.to_string(),
+            table,
+        })
+    }
+}
+
+impl fmt::Display for ConfigOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Default, Clone, clap::Args)]
 pub struct LogLevelArgs {
@@ -96,6 +152,10 @@ impl From<&LogLevelArgs> for LogLevel {
 pub enum SubCommands {
     Check(CheckArgs),
     Explain(ExplainArgs),
+    /// List rules and their metadata.
+    Rule(RuleArgs),
+    /// List or describe the available configuration options.
+    Config(ConfigArgs),
     /// Generate shell completion.
     #[clap(hide = true)]
     GenerateShellCompletion {
@@ -110,15 +170,54 @@ pub struct ExplainArgs {
     #[arg(
         value_delimiter = ',',
         value_name = "RULE_CODE",
-        value_parser = RuleSelectorParser,
+        value_parser = BuiltinRuleSelectorParser,
+        help_heading = "Rule selection",
+        hide_possible_values = true
+    )]
+    pub rules: Vec<RuleSelector>,
+}
+
+/// List rules and their metadata.
+#[derive(Debug, clap::Parser, Clone, PartialEq)]
+pub struct RuleArgs {
+    /// List of rules to show. If omitted, lists all rules.
+    #[arg(
+        value_delimiter = ',',
+        value_name = "RULE_CODE",
+        value_parser = BuiltinRuleSelectorParser,
         help_heading = "Rule selection",
         hide_possible_values = true
     )]
     pub rules: Vec<RuleSelector>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output_format: HelpFormat,
+}
+
+/// List or describe the available configuration options.
+#[derive(Debug, clap::Parser, Clone, PartialEq)]
+pub struct ConfigArgs {
+    /// Config key to show. If omitted, lists all options.
+    #[arg(value_name = "KEY")]
+    pub key: Option<String>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output_format: HelpFormat,
+}
+
+/// Output format for help-style commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HelpFormat {
+    #[default]
+    Text,
+    Json,
 }
 
 /// Perform static analysis on files and report issues.
-#[derive(Debug, clap::Parser, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, clap::Parser, Deserialize, Clone, PartialEq, Eq)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct CheckArgs {
     /// List of files or directories to check. Directories are searched recursively for
@@ -155,6 +254,12 @@ pub struct CheckArgs {
     #[clap(long, overrides_with("fix_only"), hide = true, action = SetTrue)]
     pub no_fix_only: Option<bool>,
 
+    /// Show each available fix, and ask whether to apply it. Requires fixes to
+    /// be enabled, either with `--fix` or with `fix = true` in the config file.
+    /// Combine with `--unsafe-fixes` to also review unsafe fixes.
+    #[arg(long, conflicts_with_all = ["fix_only", "watch"])]
+    pub interactive: bool,
+
     /// Ignore any `allow` comments.
     #[arg(long)]
     pub ignore_allow_comments: bool,
@@ -164,6 +269,10 @@ pub struct CheckArgs {
     #[arg(long, value_enum, env = "FORTITUDE_OUTPUT_FORMAT")]
     pub output_format: Option<OutputFormat>,
 
+    /// Maximum length of the output when using `--output-format=markdown`.
+    #[arg(long, value_name = "BYTES")]
+    pub markdown_max_length: Option<usize>,
+
     /// Specify file to write the linter output to (default: stdout).
     #[arg(short, long, env = "FORTITUDE_OUTPUT_FILE")]
     pub output_file: Option<PathBuf>,
@@ -194,6 +303,13 @@ pub struct CheckArgs {
         conflicts_with = "show_settings",
     )]
     pub show_files: bool,
+    /// Run in watch mode, checking files again whenever they or the config file change.
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["show_settings", "show_files", "statistics", "stdin_filename", "output_file"]
+    )]
+    pub watch: bool,
 
     // Rule selection
     /// Comma-separated list of rules to ignore.
@@ -318,4 +434,16 @@ pub struct CheckArgs {
     /// Show counts for every rule with at least one violation.
     #[arg(long)]
     pub statistics: bool,
+    /// Report the time spent parsing, running each rule, and applying fixes.
+    /// Times are summed across all threads. Pass `--timing=json` for machine
+    /// readable output.
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "text",
+        value_name = "FORMAT"
+    )]
+    pub timing: Option<HelpFormat>,
 }
@@ -0,0 +1,34 @@
+use std::io::{stdout, Write};
+use std::process::ExitCode;
+
+use anyhow::{anyhow, Result};
+
+use crate::cli::{ConfigArgs, HelpFormat};
+use crate::options::Options;
+use crate::options_base::OptionsMetadata;
+
+/// Print the documentation for a configuration option, or list all options.
+pub fn config(args: ConfigArgs) -> Result<ExitCode> {
+    let mut stdout = stdout().lock();
+    match args.key.as_deref() {
+        None => {
+            let metadata = Options::metadata();
+            match args.output_format {
+                HelpFormat::Text => write!(stdout, "{metadata}")?,
+                HelpFormat::Json => {
+                    writeln!(stdout, "{}", serde_json::to_string_pretty(&metadata)?)?
+                }
+            }
+        }
+        Some(key) => {
+            let entry = Options::metadata()
+                .find(key)
+                .ok_or_else(|| anyhow!("Unknown option: {key}"))?;
+            match args.output_format {
+                HelpFormat::Text => write!(stdout, "{entry}")?,
+                HelpFormat::Json => writeln!(stdout, "{}", serde_json::to_string_pretty(&entry)?)?,
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
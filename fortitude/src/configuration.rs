@@ -1,12 +1,20 @@
-use crate::cli::CheckArgs;
+use crate::cli::{CheckArgs, ConfigOverride};
+use crate::custom_rules::{self, CustomRule};
+use crate::fix_safety_table::FixSafetyTable;
 use crate::fs::{FilePattern, FilePatternSet, EXCLUDE_BUILTINS, FORTRAN_EXTS};
-use crate::options::Options;
-use crate::registry::RuleNamespace;
+use crate::options::{CustomRuleOptions, Options};
+use crate::registry::{RuleNamespace, RuleSet};
+use crate::rule_presets::Preset;
 use crate::rule_selector::{
-    collect_per_file_ignores, CompiledPerFileIgnoreList, PerFileIgnore, PreviewOptions,
+    collect_per_file_ignores, CompiledPerFileIgnoreList, ParseError, PerFileIgnore, PreviewOptions,
     RuleSelector, Specificity,
 };
 use crate::rule_table::RuleTable;
+use crate::rules::correctness::settings::{
+    AllocateStatSettings, FloatingPointEqualitySettings, PrecisionSettings,
+};
+use crate::rules::modernisation::settings::DoublePrecisionSettings;
+use crate::rules::portability::settings::UnapprovedRealKindSettings;
 use crate::rules::Rule;
 use crate::settings::{
     CheckSettings, ExcludeMode, FileResolverSettings, GitignoreMode, OutputFormat, PreviewMode,
@@ -14,7 +22,7 @@ use crate::settings::{
 };
 use crate::{fs, warn_user_once_by_id, warn_user_once_by_message};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use itertools::Itertools;
 use log::warn;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -103,7 +111,7 @@ pub fn project_root<P: AsRef<Path>>(path: P) -> Result<PathBuf> {
 
 /// Read either the "extra.fortitude" table from "fpm.toml", or the
 /// whole "fortitude.toml" file
-fn load_options<P: AsRef<Path>>(path: P) -> Result<Options> {
+pub fn load_options<P: AsRef<Path>>(path: P) -> Result<Options> {
     if path.as_ref().ends_with("fpm.toml") {
         let config = parse_fpm_toml(&path)?;
         // Unwrap should be ok here because we've already checked this
@@ -126,20 +134,49 @@ pub fn resolve_bool_arg(yes: Option<bool>, no: Option<bool>) -> Option<bool> {
     }
 }
 
-/// Read either fpm.toml or fortitude.toml into our "known good" file
-/// settings struct
-pub fn parse_config_file(config_file: &Option<PathBuf>) -> Result<Options> {
-    let filename = match config_file {
-        Some(filename) => filename.clone(),
-        None => match find_settings_toml(path_absolutize::path_dedot::CWD.as_path())? {
-            Some(filename) => filename,
-            None => {
-                return Ok(Options::default());
-            }
-        },
+/// Return the path to the configuration file to use: either the one given
+/// explicitly, or the first one found in the current directory or its ancestors
+pub fn config_file_path(config_file: &Option<PathBuf>) -> Result<Option<PathBuf>> {
+    match config_file {
+        Some(filename) => Ok(Some(filename.clone())),
+        None => find_settings_toml(path_absolutize::path_dedot::CWD.as_path()),
+    }
+}
+
+/// Apply `--config` overrides on top of the options read from a config file.
+/// Later overrides take precedence over earlier ones
+pub fn apply_config_overrides(options: Options, overrides: &[ConfigOverride]) -> Result<Options> {
+    if overrides.is_empty() {
+        return Ok(options);
+    }
+
+    let toml::Value::Table(mut table) =
+        toml::Value::try_from(&options).context("Failed to serialize configuration")?
+    else {
+        unreachable!("Options should always serialize to a table");
     };
 
-    load_options(filename)
+    for config_override in overrides {
+        merge_toml_tables(&mut table, config_override.table());
+    }
+
+    toml::Value::Table(table)
+        .try_into()
+        .context("Failed to apply `--config` overrides")
+}
+
+/// Recursively merge `other` into `base`, replacing any values that aren't tables
+fn merge_toml_tables(base: &mut toml::Table, other: &toml::Table) {
+    for (key, value) in other {
+        match (base.get_mut(key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(other_table)) => {
+                merge_toml_tables(base_table, other_table);
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
 }
 
 // This is our "known good" intermediate settings struct after we've
@@ -150,6 +187,11 @@ pub struct Configuration {
     pub ignore: Vec<RuleSelector>,
     pub select: Option<Vec<RuleSelector>>,
     pub extend_select: Vec<RuleSelector>,
+    pub preset: Option<Preset>,
+    pub fixable: Option<Vec<RuleSelector>>,
+    pub unfixable: Vec<RuleSelector>,
+    pub extend_safe_fixes: Vec<RuleSelector>,
+    pub extend_unsafe_fixes: Vec<RuleSelector>,
     pub per_file_ignores: Option<Vec<PerFileIgnore>>,
     pub line_length: usize,
     pub file_extensions: Vec<String>,
@@ -158,12 +200,19 @@ pub struct Configuration {
     pub show_fixes: bool,
     pub unsafe_fixes: UnsafeFixes,
     pub output_format: OutputFormat,
+    pub markdown_max_length: Option<usize>,
     pub progress_bar: ProgressBar,
     pub preview: PreviewMode,
     pub exclude: Option<Vec<FilePattern>>,
     pub extend_exclude: Vec<FilePattern>,
     pub exclude_mode: ExcludeMode,
     pub gitignore_mode: GitignoreMode,
+    pub custom_rules: Vec<CustomRuleOptions>,
+    pub double_precision: DoublePrecisionSettings,
+    pub unapproved_real_kind: UnapprovedRealKindSettings,
+    pub floating_point_equality: FloatingPointEqualitySettings,
+    pub precision: PrecisionSettings,
+    pub allocate_stat: AllocateStatSettings,
 }
 
 impl Default for Configuration {
@@ -173,6 +222,11 @@ impl Default for Configuration {
             ignore: Default::default(),
             select: Default::default(),
             extend_select: Default::default(),
+            preset: Default::default(),
+            fixable: Default::default(),
+            unfixable: Default::default(),
+            extend_safe_fixes: Default::default(),
+            extend_unsafe_fixes: Default::default(),
             per_file_ignores: Default::default(),
             line_length: Settings::default().check.line_length,
             file_extensions: FORTRAN_EXTS.iter().map(|ext| ext.to_string()).collect(),
@@ -181,12 +235,19 @@ impl Default for Configuration {
             show_fixes: Default::default(),
             unsafe_fixes: Default::default(),
             output_format: Default::default(),
+            markdown_max_length: Default::default(),
             progress_bar: Default::default(),
             preview: Default::default(),
             exclude: Default::default(),
             extend_exclude: Default::default(),
             exclude_mode: Default::default(),
             gitignore_mode: Default::default(),
+            custom_rules: Default::default(),
+            double_precision: Default::default(),
+            unapproved_real_kind: Default::default(),
+            floating_point_equality: Default::default(),
+            precision: Default::default(),
+            allocate_stat: Default::default(),
         }
     }
 }
@@ -201,6 +262,11 @@ impl Configuration {
             ignore: check.ignore.unwrap_or_default(),
             select: check.select,
             extend_select: check.extend_select.unwrap_or_default(),
+            preset: check.preset,
+            fixable: check.fixable,
+            unfixable: check.unfixable.unwrap_or_default(),
+            extend_safe_fixes: check.extend_safe_fixes.unwrap_or_default(),
+            extend_unsafe_fixes: check.extend_unsafe_fixes.unwrap_or_default(),
             per_file_ignores: check.per_file_ignores.map(|per_file_ignores| {
                 per_file_ignores
                     .into_iter()
@@ -223,6 +289,7 @@ impl Configuration {
                 .map(UnsafeFixes::from)
                 .unwrap_or_default(),
             output_format: check.output_format.unwrap_or_default(),
+            markdown_max_length: check.markdown_max_length,
             progress_bar: check.progress_bar.unwrap_or_default(),
             preview: check.preview.map(PreviewMode::from).unwrap_or_default(),
             exclude: check.exclude.map(|paths| {
@@ -254,6 +321,18 @@ impl Configuration {
                 .respect_gitignore
                 .map(GitignoreMode::from)
                 .unwrap_or_default(),
+            custom_rules: check.custom_rules.unwrap_or_default(),
+            double_precision: check.double_precision.unwrap_or_default().into_settings(),
+            unapproved_real_kind: check
+                .unapproved_real_kind
+                .unwrap_or_default()
+                .into_settings(),
+            floating_point_equality: check
+                .floating_point_equality
+                .unwrap_or_default()
+                .into_settings(),
+            precision: check.precision.unwrap_or_default().into_settings(),
+            allocate_stat: check.allocate_stat.unwrap_or_default().into_settings(),
         }
     }
 
@@ -307,16 +386,35 @@ impl Configuration {
             .unwrap_or(self.preview);
 
         let rule_selection = RuleSelection {
-            select: args.select.or(self.select),
+            select: args
+                .select
+                .or(self.select)
+                .or_else(|| self.preset.map(|preset| vec![RuleSelector::Preset(preset)])),
             // TODO: CLI ignore should _extend_ file ignore
             ignore: args.ignore.unwrap_or(self.ignore),
             extend_select: args.extend_select.unwrap_or(self.extend_select),
-            fixable: None,
-            unfixable: vec![],
+            fixable: self.fixable,
+            unfixable: self.unfixable,
             extend_fixable: vec![],
         };
+        let custom_rules = resolve_custom_rules(
+            self.custom_rules,
+            &rule_selection,
+            &self.extend_safe_fixes,
+            &self.extend_unsafe_fixes,
+            &per_file_ignores,
+        )?;
         let rules = to_rule_table(rule_selection, &preview)?;
 
+        let fix_safety = FixSafetyTable::from_rule_selectors(
+            &self.extend_safe_fixes,
+            &self.extend_unsafe_fixes,
+            &PreviewOptions {
+                mode: preview,
+                require_explicit: false,
+            },
+        );
+
         let mut progress_bar = args.progress_bar.unwrap_or(self.progress_bar);
         // Override progress bar settings if not using colour terminal
         if progress_bar == ProgressBar::Fancy
@@ -334,6 +432,7 @@ impl Configuration {
             check: CheckSettings {
                 project_root: project_root.to_path_buf(),
                 rules,
+                fix_safety,
                 fix: resolve_bool_arg(args.fix, args.no_fix).unwrap_or(self.fix),
                 fix_only: resolve_bool_arg(args.fix_only, args.no_fix_only)
                     .unwrap_or(self.fix_only),
@@ -344,9 +443,16 @@ impl Configuration {
                 preview,
                 progress_bar,
                 output_format,
+                markdown_max_length: args.markdown_max_length.or(self.markdown_max_length),
                 show_fixes,
                 per_file_ignores,
                 ignore_allow_comments: args.ignore_allow_comments.into(),
+                custom_rules,
+                double_precision: self.double_precision,
+                unapproved_real_kind: self.unapproved_real_kind,
+                floating_point_equality: self.floating_point_equality,
+                precision: self.precision,
+                allocate_stat: self.allocate_stat,
             },
             file_resolver: FileResolverSettings {
                 project_root: project_root.to_path_buf(),
@@ -360,6 +466,59 @@ impl Configuration {
     }
 }
 
+/// Compile the user-defined rules, working out which are selected, and check
+/// that every custom code used in a selector refers to one of them.
+fn resolve_custom_rules(
+    custom_rules: Vec<CustomRuleOptions>,
+    rule_selection: &RuleSelection,
+    extend_safe_fixes: &[RuleSelector],
+    extend_unsafe_fixes: &[RuleSelector],
+    per_file_ignores: &CompiledPerFileIgnoreList,
+) -> Result<Vec<CustomRule>> {
+    let mut names = FxHashSet::default();
+    let mut codes = FxHashSet::default();
+    let custom_rules = custom_rules
+        .into_iter()
+        .map(|options| {
+            if !names.insert(options.name.clone()) {
+                bail!("Custom rule name `{}` is used more than once", options.name);
+            }
+            if !codes.insert(options.code.clone()) {
+                bail!("Custom rule code `{}` is used more than once", options.code);
+            }
+            let enabled = custom_rules::is_selected(
+                &options.code,
+                rule_selection.select.as_deref(),
+                &rule_selection.extend_select,
+                &rule_selection.ignore,
+            );
+            CustomRule::new(options, enabled)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let selected_codes = rule_selection
+        .selectors_by_kind()
+        .map(|(_, selector)| selector)
+        .chain(extend_safe_fixes)
+        .chain(extend_unsafe_fixes)
+        .filter_map(|selector| match selector {
+            RuleSelector::Custom(code) => Some(code.as_str()),
+            _ => None,
+        })
+        .chain(
+            per_file_ignores
+                .iter()
+                .flat_map(|ignore| ignore.custom_rules.iter().map(String::as_str)),
+        );
+    for code in selected_codes {
+        if !codes.contains(code) {
+            bail!(ParseError::Unknown(code.to_string()));
+        }
+    }
+
+    Ok(custom_rules)
+}
+
 /// Get the list of active rules for this session.
 pub fn to_rule_table(args: RuleSelection, preview: &PreviewMode) -> anyhow::Result<RuleTable> {
     let preview = PreviewOptions {
@@ -546,10 +705,39 @@ pub fn to_rule_table(args: RuleSelection, preview: &PreviewMode) -> anyhow::Resu
         );
     }
 
+    // The fixable_set keeps track of which rules are fixable. Unlike
+    // select_set, this considers all rules, regardless of preview mode,
+    // so that it doesn't matter whether a rule is also selected
+    let mut fixable_set: RuleSet = if args.fixable.is_none() {
+        RuleSelector::All.all_rules().collect()
+    } else {
+        RuleSet::empty()
+    };
+
+    for spec in Specificity::iter() {
+        for selector in args
+            .fixable
+            .iter()
+            .flatten()
+            .chain(args.extend_fixable.iter())
+            .filter(|s| s.specificity() == spec)
+        {
+            for rule in selector.all_rules() {
+                fixable_set.insert(rule);
+            }
+        }
+
+        for selector in args.unfixable.iter().filter(|s| s.specificity() == spec) {
+            for rule in selector.all_rules() {
+                fixable_set.remove(rule);
+            }
+        }
+    }
+
     let mut rules = RuleTable::empty();
 
     for rule in select_set {
-        let should_fix = true;
+        let should_fix = fixable_set.contains(rule);
         rules.enable(rule, should_fix);
     }
 
@@ -761,6 +949,76 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn unfixable_rules() -> anyhow::Result<()> {
+        let args = RuleSelection {
+            ignore: vec![],
+            select: Some(vec![RuleSelector::from_str("S")?]),
+            extend_select: vec![],
+            fixable: None,
+            extend_fixable: vec![],
+            unfixable: vec![RuleSelector::from_str("S201")?],
+        };
+
+        let rules = to_rule_table(args, &PreviewMode::default())?;
+
+        assert!(rules.enabled(Rule::SuperfluousImplicitNone));
+        assert!(!rules.should_fix(Rule::SuperfluousImplicitNone));
+        assert!(rules.should_fix(Rule::MissingDoubleColon));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fixable_overrides_unfixable_with_more_specific_selector() -> anyhow::Result<()> {
+        let args = RuleSelection {
+            ignore: vec![],
+            select: Some(vec![RuleSelector::from_str("S")?]),
+            extend_select: vec![],
+            fixable: Some(vec![RuleSelector::from_str("S201")?]),
+            extend_fixable: vec![],
+            unfixable: vec![RuleSelector::from_str("S")?],
+        };
+
+        let rules = to_rule_table(args, &PreviewMode::default())?;
+
+        assert!(rules.should_fix(Rule::SuperfluousImplicitNone));
+        assert!(!rules.should_fix(Rule::MissingDoubleColon));
+
+        Ok(())
+    }
+
+    #[test]
+    fn select_preset() -> anyhow::Result<()> {
+        let args = RuleSelection {
+            ignore: vec![RuleSelector::from_str("C001")?],
+            select: Some(vec![RuleSelector::from_str("preset:legacy")?]),
+            extend_select: vec![],
+            fixable: None,
+            extend_fixable: vec![],
+            unfixable: vec![],
+        };
+
+        let preview_mode = PreviewMode::default();
+        let rules = resolve_rules(args, &preview_mode)?;
+        let preview = PreviewOptions::default();
+
+        let expected: Vec<Rule> = Preset::Legacy
+            .rules()
+            .filter(|rule| *rule != Rule::ImplicitTyping)
+            .filter(|rule| !rule.is_preview() && !rule.is_removed() && !rule.is_deprecated())
+            .collect();
+
+        assert_eq!(rules, RuleSet::from_rules(&expected));
+        assert!(!rules.contains(Rule::LineTooLong));
+        assert_eq!(
+            RuleSelector::Preset(Preset::Legacy).rules(&preview).count(),
+            expected.len() + 1
+        );
+
+        Ok(())
+    }
+
     use std::fs;
 
     use anyhow::{Context, Result};
@@ -790,4 +1048,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn config_overrides_merge_with_file() -> Result<()> {
+        let options: Options = toml::from_str(&dedent(
+            r#"
+            [check]
+            select = ["C", "S"]
+            line-length = 100
+            "#,
+        ))?;
+
+        let overrides = [
+            ConfigOverride::from_str("check.line-length = 120").map_err(anyhow::Error::msg)?,
+            ConfigOverride::from_str("check.line-length = 132").map_err(anyhow::Error::msg)?,
+        ];
+        let options = apply_config_overrides(options, &overrides)?;
+        let check = options.check.context("Missing check section")?;
+
+        assert_eq!(check.line_length, Some(132));
+        assert_eq!(
+            check.select,
+            Some(vec![
+                RuleSelector::from_str("C")?,
+                RuleSelector::from_str("S")?
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_config_override() {
+        assert!(ConfigOverride::from_str("check.not-an-option = 1").is_err());
+        assert!(ConfigOverride::from_str("check.line-length").is_err());
+    }
 }
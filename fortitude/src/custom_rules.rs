@@ -0,0 +1,360 @@
+//! User-defined rules, configured with `[[check.custom-rules]]`, which report
+//! a violation for each match of a tree-sitter query.
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+use lazy_regex::{regex_is_match, Regex, RegexBuilder};
+use ruff_diagnostics::{Diagnostic, DiagnosticKind};
+use ruff_source_file::SourceFile;
+use ruff_text_size::{TextRange, TextSize};
+use rustc_hash::FxHashSet;
+use streaming_iterator::StreamingIterator;
+use strum::IntoEnumIterator;
+use tree_sitter::{Query, QueryCursor, Tree};
+
+use crate::options::CustomRuleOptions;
+use crate::registry::{Category, Rule, RuleNamespace};
+use crate::rule_selector::{RuleSelector, Specificity};
+
+/// Violations are reported on the capture with this name, if the query has
+/// one, and on the first capture otherwise.
+const VIOLATION_CAPTURE: &str = "violation";
+
+/// A rule from the `custom-rules` setting, ready to be checked.
+#[derive(Debug)]
+pub struct CustomRule {
+    pub name: String,
+    pub code: String,
+    pub message: String,
+    /// Whether the rule has been selected.
+    pub enabled: bool,
+    query: Query,
+    /// Patterns that the text of a capture must match, by capture index.
+    predicates: Vec<(u32, Regex)>,
+    violation_capture: u32,
+}
+
+impl CustomRule {
+    /// Validate the rule and compile its query.
+    pub fn new(options: CustomRuleOptions, enabled: bool) -> Result<Self> {
+        let CustomRuleOptions {
+            name,
+            code,
+            message,
+            query,
+            predicates,
+        } = options;
+
+        if !regex_is_match!(r"^[a-z][a-z0-9]*(-[a-z0-9]+)*$", &name) {
+            bail!("Invalid name `{name}` for custom rule: names must be lowercase words separated by hyphens");
+        }
+        if Rule::from_str(&name).is_ok() {
+            bail!("Custom rule `{name}` has the same name as a built-in rule");
+        }
+        if !is_custom_code(&code) {
+            bail!(
+                "Invalid code `{code}` for custom rule `{name}`: codes must be uppercase letters followed by digits, and can't use the prefix of a built-in category"
+            );
+        }
+
+        let query = Query::new(&tree_sitter_fortran::LANGUAGE.into(), &query)
+            .map_err(|err| anyhow!("Invalid query for custom rule `{name}`: {err}"))?;
+
+        let violation_capture = match query.capture_index_for_name(VIOLATION_CAPTURE) {
+            Some(index) => index,
+            None if !query.capture_names().is_empty() => 0,
+            None => bail!("The query for custom rule `{name}` must have at least one capture"),
+        };
+
+        let mut predicates = predicates
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(capture, pattern)| {
+                let index = query.capture_index_for_name(&capture).ok_or_else(|| {
+                    anyhow!("Custom rule `{name}` has a predicate for unknown capture `@{capture}`")
+                })?;
+                // Fortran is case-insensitive, so its names should be too
+                let regex = RegexBuilder::new(&pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|err| {
+                        anyhow!("Invalid predicate for `@{capture}` in custom rule `{name}`: {err}")
+                    })?;
+                Ok((index, regex))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        predicates.sort_by_key(|(index, _)| *index);
+
+        Ok(Self {
+            name,
+            code,
+            message,
+            enabled,
+            query,
+            predicates,
+            violation_capture,
+        })
+    }
+
+    /// Report a violation for every match of the query in `tree`.
+    fn check(&self, tree: &Tree, file: &SourceFile) -> Vec<Diagnostic> {
+        let source = file.source_text();
+        let capture_names = self.query.capture_names();
+
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&self.query, tree.root_node(), source.as_bytes());
+
+        let mut seen = FxHashSet::default();
+        let mut violations = Vec::new();
+        while let Some(query_match) = matches.next() {
+            let capture_text = |index: u32| {
+                query_match
+                    .nodes_for_capture_index(index)
+                    .next()
+                    .and_then(|node| node.utf8_text(source.as_bytes()).ok())
+            };
+
+            let satisfied = self.predicates.iter().all(|(index, regex)| {
+                capture_text(*index).is_some_and(|text| regex.is_match(text))
+            });
+            if !satisfied {
+                continue;
+            }
+
+            let Some(node) = query_match
+                .nodes_for_capture_index(self.violation_capture)
+                .next()
+            else {
+                continue;
+            };
+            let range = TextRange::new(
+                TextSize::try_from(node.start_byte()).unwrap(),
+                TextSize::try_from(node.end_byte()).unwrap(),
+            );
+            // Several patterns in a query may match the same code
+            if !seen.insert(range) {
+                continue;
+            }
+
+            // Replace `{capture}` in the message with the text of that capture
+            let mut body = self.message.clone();
+            for (index, capture) in capture_names.iter().enumerate() {
+                if let Some(text) = capture_text(index as u32) {
+                    body = body.replace(&format!("{{{capture}}}"), text);
+                }
+            }
+
+            violations.push(Diagnostic::new(
+                DiagnosticKind {
+                    name: self.name.clone(),
+                    body,
+                    suggestion: None,
+                },
+                range,
+            ));
+        }
+        violations
+    }
+}
+
+impl fmt::Display for CustomRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.code, self.name)?;
+        if !self.enabled {
+            write!(f, " (disabled)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns true if `code` could be the code of a custom rule: some uppercase
+/// letters followed by some digits, where the letters aren't the prefix of
+/// one of the built-in categories.
+pub(crate) fn is_custom_code(code: &str) -> bool {
+    if !regex_is_match!(r"^[A-Z]+[0-9]+$", code) {
+        return false;
+    }
+    let prefix = code.trim_end_matches(|c: char| c.is_ascii_digit());
+    Category::iter().all(|category| category.common_prefix() != prefix)
+}
+
+/// Returns true if the custom rule with the given code is selected. Custom
+/// rules are enabled unless `select` is given, and can only be selected or
+/// ignored by their exact code or with `ALL`. As for built-in rules, more
+/// specific selectors take precedence.
+pub(crate) fn is_selected(
+    code: &str,
+    select: Option<&[RuleSelector]>,
+    extend_select: &[RuleSelector],
+    ignore: &[RuleSelector],
+) -> bool {
+    let matches = |selector: &RuleSelector| match selector {
+        RuleSelector::All => true,
+        RuleSelector::Custom(custom) => custom == code,
+        _ => false,
+    };
+
+    let mut selected = select.is_none();
+    for spec in Specificity::iter() {
+        if select
+            .into_iter()
+            .flatten()
+            .chain(extend_select)
+            .filter(|selector| selector.specificity() == spec)
+            .any(matches)
+        {
+            selected = true;
+        }
+        if ignore
+            .iter()
+            .filter(|selector| selector.specificity() == spec)
+            .any(matches)
+        {
+            selected = false;
+        }
+    }
+    selected
+}
+
+/// Check all the enabled custom rules.
+pub(crate) fn check_custom_rules(
+    custom_rules: &[CustomRule],
+    tree: &Tree,
+    file: &SourceFile,
+) -> Vec<Diagnostic> {
+    custom_rules
+        .iter()
+        .filter(|rule| rule.enabled)
+        .flat_map(|rule| rule.check(tree, file))
+        .collect()
+}
+
+/// Returns the code of the custom rule that raised a violation with this name.
+pub(crate) fn code_for_name<'a>(custom_rules: &'a [CustomRule], name: &str) -> Option<&'a str> {
+    custom_rules
+        .iter()
+        .find(|rule| rule.name == name)
+        .map(|rule| rule.code.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use test_case::test_case;
+    use tree_sitter::Parser;
+
+    use super::{is_custom_code, is_selected, CustomRule};
+    use crate::options::CustomRuleOptions;
+    use crate::test_file;
+
+    fn options(query: &str) -> CustomRuleOptions {
+        CustomRuleOptions {
+            name: "no-mpi-abort".to_string(),
+            code: "HOUSE001".to_string(),
+            message: "Don't call `{function}`".to_string(),
+            query: query.to_string(),
+            predicates: None,
+        }
+    }
+
+    #[test_case("HOUSE001", true; "custom prefix")]
+    #[test_case("X1", true; "short")]
+    #[test_case("C001", false; "built-in prefix")]
+    #[test_case("PORT999", false; "unused built-in code")]
+    #[test_case("house001", false; "lowercase")]
+    #[test_case("HOUSE", false; "no digits")]
+    #[test_case("HOUSE-001", false; "punctuation")]
+    fn custom_codes(code: &str, expected: bool) {
+        assert_eq!(is_custom_code(code), expected);
+    }
+
+    #[test_case(None, &[], &[], true; "default")]
+    #[test_case(Some(&["C"]), &[], &[], false; "not selected")]
+    #[test_case(Some(&["HOUSE001"]), &[], &[], true; "selected")]
+    #[test_case(Some(&["C"]), &["HOUSE001"], &[], true; "extend select")]
+    #[test_case(Some(&["ALL"]), &[], &[], true; "select all")]
+    #[test_case(None, &[], &["HOUSE001"], false; "ignored")]
+    #[test_case(Some(&["HOUSE001"]), &[], &["ALL"], true; "more specific select")]
+    #[test_case(Some(&["HOUSE002"]), &[], &[], false; "other code")]
+    fn selection(select: Option<&[&str]>, extend_select: &[&str], ignore: &[&str], expected: bool) {
+        let parse =
+            |codes: &[&str]| -> Vec<_> { codes.iter().map(|c| c.parse().unwrap()).collect() };
+        let select = select.map(parse);
+        assert_eq!(
+            is_selected(
+                "HOUSE001",
+                select.as_deref(),
+                &parse(extend_select),
+                &parse(ignore)
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn matches_with_predicates() -> Result<()> {
+        let mut options = options("(subroutine_call (identifier) @function) @violation");
+        options.predicates = Some(
+            [("function".to_string(), "^mpi_abort$".to_string())]
+                .into_iter()
+                .collect(),
+        );
+        let rule = CustomRule::new(options, true)?;
+
+        let file = test_file(
+            "
+            program test
+              call MPI_Abort(comm, 1, ierr)
+              call mpi_finalize(ierr)
+            end program test
+            ",
+        );
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_fortran::LANGUAGE.into())?;
+        let tree = parser.parse(file.source_text(), None).unwrap();
+
+        let violations = rule.check(&tree, &file);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].kind.name, "no-mpi-abort");
+        assert_eq!(violations[0].kind.body, "Don't call `MPI_Abort`");
+        assert_eq!(
+            &file.source_text()[violations[0].range],
+            "call MPI_Abort(comm, 1, ierr)"
+        );
+        Ok(())
+    }
+
+    #[test_case("(subroutine_call", "Invalid query"; "invalid query")]
+    #[test_case("(not_a_node) @violation", "Invalid query"; "unknown node")]
+    #[test_case("(subroutine_call)", "at least one capture"; "no captures")]
+    fn invalid_queries(query: &str, error: &str) {
+        let err = CustomRule::new(options(query), true).unwrap_err();
+        assert!(err.to_string().contains(error), "{err}");
+    }
+
+    #[test]
+    fn unknown_predicate_capture() {
+        let mut options = options("(subroutine_call) @violation");
+        options.predicates = Some(
+            [("function".to_string(), "mpi_abort".to_string())]
+                .into_iter()
+                .collect(),
+        );
+        let err = CustomRule::new(options, true).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown capture `@function`"),
+            "{err}"
+        );
+    }
+
+    #[test_case("NoMpiAbort", "HOUSE001"; "invalid name")]
+    #[test_case("implicit-typing", "HOUSE001"; "built-in name")]
+    #[test_case("no-mpi-abort", "S999"; "built-in prefix")]
+    fn invalid_identity(name: &str, code: &str) {
+        let mut options = options("(subroutine_call) @violation");
+        options.name = name.to_string();
+        options.code = code.to_string();
+        assert!(CustomRule::new(options, true).is_err());
+    }
+}
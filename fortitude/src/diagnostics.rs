@@ -8,10 +8,12 @@ use rustc_hash::FxHashMap;
 
 use crate::{fix::FixTable, message::DiagnosticMessage};
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct Diagnostics {
     pub(crate) messages: Vec<DiagnosticMessage>,
     pub(crate) fixed: FixMap,
+    /// The number of fixes withheld because their rules are marked as unfixable.
+    pub(crate) fixes_withheld: usize,
 }
 
 impl Diagnostics {
@@ -19,6 +21,7 @@ impl Diagnostics {
         Self {
             messages,
             fixed: FixMap::default(),
+            fixes_withheld: 0,
         }
     }
 
@@ -40,11 +43,12 @@ impl AddAssign for Diagnostics {
     fn add_assign(&mut self, other: Self) {
         self.messages.extend(other.messages);
         self.fixed += other.fixed;
+        self.fixes_withheld += other.fixes_withheld;
     }
 }
 
 /// A collection of fixes indexed by file path.
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct FixMap(FxHashMap<String, FixTable>);
 
 impl FixMap {
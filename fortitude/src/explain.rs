@@ -2,6 +2,7 @@ use std::collections::BTreeSet;
 use std::process::ExitCode;
 
 use crate::cli::ExplainArgs;
+use crate::rule_presets::Preset;
 use crate::rule_selector::PreviewOptions;
 use crate::rules::Rule;
 use crate::settings::DEFAULT_SELECTORS;
@@ -63,6 +64,16 @@ pub fn explain(args: ExplainArgs) -> Result<ExitCode> {
             body.push('\n');
         }
 
+        let presets = Preset::containing(rule);
+        if !presets.is_empty() {
+            body.push_str(&format!(
+                "This rule is included in the following presets: {}.",
+                presets.iter().join(", ")
+            ));
+            body.push('\n');
+            body.push('\n');
+        }
+
         if let Some(explanation) = rule.explanation() {
             body.push_str(explanation);
         } else {
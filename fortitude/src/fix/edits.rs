@@ -0,0 +1,148 @@
+//! Helpers for building fixes that need more than a local edit
+
+use ruff_diagnostics::Edit;
+use ruff_source_file::{LineRanges, SourceFile};
+use ruff_text_size::TextSize;
+use tree_sitter::Node;
+
+use crate::ast::FortitudeNode;
+use crate::rules::correctness::use_statements::INTRINSIC_MODULES;
+
+/// Nodes that can contain `use` statements
+const SCOPES: &[&str] = &[
+    "program",
+    "module",
+    "submodule",
+    "function",
+    "subroutine",
+    "module_procedure",
+];
+
+/// Get the scopes that `node` can see entities from, innermost first.
+/// Interface bodies don't have access to their host, so stop there.
+pub(crate) fn enclosing_scopes<'a>(node: &'a Node) -> Vec<Node<'a>> {
+    let mut scopes = Vec::new();
+    for ancestor in node.ancestors() {
+        if ancestor.kind() == "interface" {
+            break;
+        }
+        if SCOPES.contains(&ancestor.kind()) {
+            scopes.push(ancestor);
+        }
+    }
+    scopes
+}
+
+/// Get the `use` statements for `module` that are directly in `scope`
+fn use_statements<'a>(scope: &Node<'a>, module: &str, src: &str) -> Vec<Node<'a>> {
+    scope
+        .named_children(&mut scope.walk())
+        .filter(|child| child.kind() == "use_statement")
+        .filter(|child| {
+            child
+                .child_with_name("module_name")
+                .and_then(|name| name.to_text(src))
+                .is_some_and(|name| name.eq_ignore_ascii_case(module))
+        })
+        .collect()
+}
+
+/// Is `name` made available by a `use` statement, either because it's in the
+/// `only` list, or because there is no `only` list
+fn use_provides(statement: &Node, name: &str, src: &str) -> bool {
+    let Some(items) = statement.child_with_name("included_items") else {
+        return true;
+    };
+    items.named_children(&mut items.walk()).any(|item| {
+        // For renames, such as `dp => real64`, the local name comes first
+        let local = match item.kind() {
+            "use_alias" => item.named_child(0),
+            _ => Some(item),
+        };
+        local
+            .and_then(|local| local.to_text(src))
+            .is_some_and(|local| local.eq_ignore_ascii_case(name))
+    })
+}
+
+/// Get the position at the start of the line after `node`
+fn start_of_next_line(node: &Node, src: &str) -> TextSize {
+    // The node might include the end-of-statement newline, so don't
+    // include trailing whitespace
+    let text = node.to_text(src).unwrap_or_default();
+    let end = node.start_byte() + text.trim_end().len();
+    src.full_line_end(TextSize::try_from(end).unwrap())
+}
+
+/// Get the edits needed so that `name` from `module` can be used at `node`.
+///
+/// If `name` is already available, no edits are needed. Otherwise, it's added
+/// to the `only` list of an existing `use` statement for `module`, if there is
+/// one, or a new `use` statement is added after any others in the innermost
+/// scope, and so before any `implicit` statements.
+///
+/// Returns `None` if `node` isn't inside a scope that can contain `use`
+/// statements.
+pub(crate) fn ensure_use_statement(
+    node: &Node,
+    module: &str,
+    name: &str,
+    source_file: &SourceFile,
+) -> Option<Vec<Edit>> {
+    let src = source_file.source_text();
+    let scopes = enclosing_scopes(node);
+    let innermost = scopes.first()?;
+
+    let statements: Vec<Node> = scopes
+        .iter()
+        .flat_map(|scope| use_statements(scope, module, src))
+        .collect();
+
+    if statements
+        .iter()
+        .any(|statement| use_provides(statement, name, src))
+    {
+        return Some(Vec::new());
+    }
+
+    // Add to an existing `only` list
+    if let Some(items) = statements
+        .iter()
+        .find_map(|statement| statement.child_with_name("included_items"))
+    {
+        let text = items.to_text(src)?;
+        let end = items.start_byte() + text.trim_end().len();
+        return Some(vec![Edit::insertion(
+            format!(", {name}"),
+            TextSize::try_from(end).unwrap(),
+        )]);
+    }
+
+    // Otherwise, add a new statement after the last `use`, or straight after
+    // the start of the scope
+    let last_use = innermost
+        .named_children(&mut innermost.walk())
+        .filter(|child| child.kind() == "use_statement")
+        .last();
+    let (anchor, indentation) = match last_use {
+        Some(last_use) => (last_use, last_use.indentation(source_file)),
+        None => {
+            let statement = innermost.named_child(0)?;
+            let indentation = match innermost.named_child(1) {
+                Some(first) if !first.kind().starts_with("end_") => first.indentation(source_file),
+                _ => format!("{}  ", statement.indentation(source_file)),
+            };
+            (statement, indentation)
+        }
+    };
+
+    let intrinsic = if INTRINSIC_MODULES.contains(&module.to_lowercase().as_str()) {
+        ", intrinsic"
+    } else {
+        ""
+    };
+    Some(vec![Edit::insertion(
+        format!("{indentation}use{intrinsic} :: {module}, only: {name}\n"),
+        start_of_next_line(&anchor, src),
+    )])
+}
@@ -13,8 +13,10 @@ use ruff_text_size::{Ranged, TextLen, TextRange, TextSize};
 
 use crate::locator::Locator;
 use crate::registry::{AsRule, Rule};
+use crate::rule_table::RuleTable;
 use crate::settings::UnsafeFixes;
 
+pub(crate) mod edits;
 pub(crate) mod snippet;
 
 pub type FixTable = FxHashMap<Rule, usize>;
@@ -30,9 +32,11 @@ pub(crate) struct FixResult {
 }
 
 /// Fix errors in a file, and write the fixed source code to disk.
+/// Fixes for rules that have been marked as unfixable are skipped.
 pub(crate) fn fix_file(
     diagnostics: &[Diagnostic],
     locator: &Locator,
+    rules: &RuleTable,
     unsafe_fixes: UnsafeFixes,
     name: &str,
 ) -> Option<FixResult> {
@@ -41,10 +45,12 @@ pub(crate) fn fix_file(
     let mut with_fixes = diagnostics
         .iter()
         .filter(|diagnostic| {
+            // Check for a fix first, as custom rules have no fixes and aren't in the registry
             diagnostic
                 .fix
                 .as_ref()
                 .is_some_and(|fix| fix.applies(required_applicability))
+                && rules.should_fix(diagnostic.kind.rule())
         })
         .peekable();
 
@@ -56,7 +62,7 @@ pub(crate) fn fix_file(
 }
 
 /// Apply a series of fixes.
-fn apply_fixes<'a>(
+pub(crate) fn apply_fixes<'a>(
     diagnostics: impl Iterator<Item = &'a Diagnostic>,
     locator: &'a Locator<'a>,
     name: &str,
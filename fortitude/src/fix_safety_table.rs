@@ -0,0 +1,177 @@
+// Adapted from ruff
+// Copyright 2022 Charles Marsh
+// SPDX-License-Identifier: MIT
+
+use std::fmt::{Debug, Display, Formatter};
+
+use itertools::{Either, Itertools};
+use ruff_diagnostics::Applicability;
+use rustc_hash::FxHashMap;
+use strum::IntoEnumIterator;
+
+use crate::display_settings;
+use crate::registry::{Rule, RuleSet};
+use crate::rule_selector::{PreviewOptions, RuleSelector, Specificity};
+
+/// A table to keep track of which rules' fixes should have their safety overridden.
+#[derive(Debug, Clone, Default)]
+pub struct FixSafetyTable {
+    forced_safe: RuleSet,
+    forced_unsafe: RuleSet,
+}
+
+impl FixSafetyTable {
+    /// Returns the applicability of a fix for the given rule, after applying
+    /// any user overrides. Display-only fixes are never modified.
+    pub const fn resolve_applicability(
+        &self,
+        rule: Rule,
+        applicability: Applicability,
+    ) -> Applicability {
+        match applicability {
+            Applicability::DisplayOnly => applicability,
+            Applicability::Safe | Applicability::Unsafe => {
+                if self.forced_unsafe.contains(rule) {
+                    Applicability::Unsafe
+                } else if self.forced_safe.contains(rule) {
+                    Applicability::Safe
+                } else {
+                    applicability
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_rule_selectors(
+        extend_safe_fixes: &[RuleSelector],
+        extend_unsafe_fixes: &[RuleSelector],
+        preview_options: &PreviewOptions,
+    ) -> Self {
+        enum Override {
+            Safe,
+            Unsafe,
+        }
+
+        let safety_override_map: FxHashMap<Rule, Override> = {
+            Specificity::iter()
+                .flat_map(|spec| {
+                    let safe_overrides = extend_safe_fixes
+                        .iter()
+                        .filter(|selector| selector.specificity() == spec)
+                        .flat_map(|selector| selector.rules(preview_options))
+                        .map(|rule| (rule, Override::Safe));
+
+                    let unsafe_overrides = extend_unsafe_fixes
+                        .iter()
+                        .filter(|selector| selector.specificity() == spec)
+                        .flat_map(|selector| selector.rules(preview_options))
+                        .map(|rule| (rule, Override::Unsafe));
+
+                    // Unsafe overrides take precedence over safe overrides
+                    safe_overrides.chain(unsafe_overrides).collect::<Vec<_>>()
+                })
+                // More specified selectors take precedence over less specified selectors
+                .collect()
+        };
+
+        let (forced_safe, forced_unsafe): (Vec<Rule>, Vec<Rule>) = safety_override_map
+            .into_iter()
+            .partition_map(|(rule, safety_override)| match safety_override {
+                Override::Safe => Either::Left(rule),
+                Override::Unsafe => Either::Right(rule),
+            });
+
+        Self {
+            forced_safe: forced_safe.into_iter().collect(),
+            forced_unsafe: forced_unsafe.into_iter().collect(),
+        }
+    }
+}
+
+impl Display for FixSafetyTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        display_settings! {
+            formatter = f,
+            namespace = "check.fix_safety",
+            fields = [
+                self.forced_safe,
+                self.forced_unsafe,
+            ]
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn check_resolve_fix_safety() {
+        let table = FixSafetyTable {
+            forced_safe: RuleSet::from_rules(&[Rule::ImplicitTyping]),
+            forced_unsafe: RuleSet::from_rules(&[Rule::SuperfluousImplicitNone]),
+        };
+
+        for (rule, applicability, expected) in [
+            (
+                Rule::ImplicitTyping,
+                Applicability::Unsafe,
+                Applicability::Safe,
+            ),
+            (
+                Rule::SuperfluousImplicitNone,
+                Applicability::Safe,
+                Applicability::Unsafe,
+            ),
+            (
+                Rule::SuperfluousImplicitNone,
+                Applicability::DisplayOnly,
+                Applicability::DisplayOnly,
+            ),
+            (
+                Rule::MissingDoubleColon,
+                Applicability::Safe,
+                Applicability::Safe,
+            ),
+        ] {
+            assert_eq!(
+                table.resolve_applicability(rule, applicability),
+                expected,
+                "{rule:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_unsafe_overrides_safe() -> anyhow::Result<()> {
+        let preview = PreviewOptions::default();
+        let table = FixSafetyTable::from_rule_selectors(
+            &[RuleSelector::from_str("S")?],
+            &[RuleSelector::from_str("S")?, RuleSelector::from_str("C")?],
+            &preview,
+        );
+        assert_eq!(
+            table.resolve_applicability(Rule::SuperfluousImplicitNone, Applicability::Safe),
+            Applicability::Unsafe
+        );
+
+        // More specific selectors win
+        let table = FixSafetyTable::from_rule_selectors(
+            &[RuleSelector::from_str("S201")?],
+            &[RuleSelector::from_str("S")?],
+            &preview,
+        );
+        assert_eq!(
+            table.resolve_applicability(Rule::SuperfluousImplicitNone, Applicability::Unsafe),
+            Applicability::Safe
+        );
+        assert_eq!(
+            table.resolve_applicability(Rule::MissingDoubleColon, Applicability::Safe),
+            Applicability::Unsafe
+        );
+        Ok(())
+    }
+}
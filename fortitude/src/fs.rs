@@ -14,7 +14,7 @@ use ruff_macros::CacheKey;
 use serde::{de, Deserialize, Deserializer, Serialize};
 
 use crate::registry::Rule;
-use crate::rule_selector::CompiledPerFileIgnoreList;
+use crate::rule_selector::{CompiledPerFileIgnore, CompiledPerFileIgnoreList};
 use crate::settings::FileResolverSettings;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Serialize, CacheKey)]
@@ -177,26 +177,34 @@ impl FilePatternSet {
 
 /// Create a set with codes matching the pattern/code pairs.
 pub(crate) fn ignores_from_path(path: &Path, ignore_list: &CompiledPerFileIgnoreList) -> Vec<Rule> {
-    let file_name = path.file_name().expect("Unable to parse filename");
-    ignore_list
-        .iter()
-        .filter_map(|entry| {
-            if entry.basename_matcher.is_match(file_name) || entry.absolute_matcher.is_match(path) {
-                if entry.negated {
-                    None
-                } else {
-                    Some(&entry.rules)
-                }
-            } else if entry.negated {
-                Some(&entry.rules)
-            } else {
-                None
-            }
-        })
-        .flatten()
+    matching_ignores(path, ignore_list)
+        .flat_map(|entry| &entry.rules)
+        .collect()
+}
+
+/// Get the codes of the custom rules ignored for this path.
+pub(crate) fn custom_ignores_from_path<'a>(
+    path: &Path,
+    ignore_list: &'a CompiledPerFileIgnoreList,
+) -> Vec<&'a str> {
+    matching_ignores(path, ignore_list)
+        .flat_map(|entry| entry.custom_rules.iter().map(String::as_str))
         .collect()
 }
 
+/// The per-file-ignores entries which apply to this path.
+fn matching_ignores<'a>(
+    path: &'a Path,
+    ignore_list: &'a CompiledPerFileIgnoreList,
+) -> impl Iterator<Item = &'a CompiledPerFileIgnore> {
+    let file_name = path.file_name().expect("Unable to parse filename");
+    ignore_list.iter().filter(move |entry| {
+        let matched =
+            entry.basename_matcher.is_match(file_name) || entry.absolute_matcher.is_match(path);
+        matched != entry.negated
+    })
+}
+
 /// Convert any path to an absolute path (based on the current working
 /// directory).
 pub fn normalize_path<P: AsRef<Path>>(path: P) -> PathBuf {
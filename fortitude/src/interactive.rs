@@ -0,0 +1,265 @@
+//! Review fixes one at a time before applying them, for
+//! `fortitude check --fix --interactive`.
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use itertools::Itertools;
+use ruff_diagnostics::Diagnostic;
+use ruff_source_file::SourceFileBuilder;
+use ruff_text_size::Ranged;
+use rustc_hash::FxHashSet;
+use tree_sitter::Parser;
+
+use crate::check::{check_path, read_to_string, report_fix_syntax_error, AstEntrypoints};
+use crate::diagnostics::FixMap;
+use crate::fix::{apply_fixes, FixResult, FixTable};
+use crate::locator::Locator;
+use crate::message::{DiagnosticMessage, Emitter, TextEmitter};
+use crate::registry::{AsRule, Rule};
+use crate::rule_table::RuleTable;
+use crate::rules::{PathRuleEnum, TextRuleEnum};
+use crate::settings::Settings;
+use crate::{fs, warn_user_once_by_message};
+
+/// What to do with a fix.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Choice {
+    /// Apply this fix.
+    Accept,
+    /// Don't apply this fix.
+    Skip,
+    /// Apply this fix, and all later fixes for the same rule without asking.
+    AcceptRule,
+    /// Stop reviewing fixes. Fixes already accepted are still applied.
+    Quit,
+}
+
+/// Ask what to do with a fix, repeating the question until we get a valid
+/// answer. Reaching the end of the input is treated as quitting.
+fn prompt(rule: Rule, reader: &mut dyn BufRead, writer: &mut dyn Write) -> Result<Choice> {
+    loop {
+        write!(
+            writer,
+            "Apply this fix? [{}]ccept, [{}]kip, accept a[{}]l {} fixes, [{}]uit: ",
+            "a".bold(),
+            "s".bold(),
+            "l".bold(),
+            rule.as_ref(),
+            "q".bold(),
+        )?;
+        writer.flush()?;
+
+        let mut answer = String::new();
+        if reader.read_line(&mut answer)? == 0 {
+            writeln!(writer)?;
+            return Ok(Choice::Quit);
+        }
+        match answer.trim() {
+            "a" | "accept" => return Ok(Choice::Accept),
+            "s" | "skip" => return Ok(Choice::Skip),
+            "l" | "all" => return Ok(Choice::AcceptRule),
+            "q" | "quit" => return Ok(Choice::Quit),
+            _ => continue,
+        }
+    }
+}
+
+/// Check each file, asking whether to apply each fix, and write the accepted
+/// fixes back to the files. Only fixes allowed by the `fixable`/`unfixable`
+/// and `unsafe-fixes` settings are offered. Returns the fixes applied to
+/// each file.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn review_fixes(
+    files: &[PathBuf],
+    rules: &RuleTable,
+    path_rules: &Vec<PathRuleEnum>,
+    text_rules: &Vec<TextRuleEnum>,
+    ast_entrypoints: &AstEntrypoints,
+    settings: &Settings,
+    reader: &mut dyn BufRead,
+    writer: &mut dyn Write,
+) -> Result<FixMap> {
+    let mut reviewer = Reviewer {
+        rules,
+        path_rules,
+        text_rules,
+        ast_entrypoints,
+        settings,
+        accepted_rules: FxHashSet::default(),
+        quit: false,
+    };
+
+    let mut fixed = Vec::new();
+    for path in files {
+        if reviewer.quit {
+            break;
+        }
+        if let Some(fixes) = reviewer.review_file(path, reader, writer)? {
+            fixed.push((fs::relativize_path(path), fixes));
+        }
+    }
+    Ok(FixMap::from_iter(fixed))
+}
+
+struct Reviewer<'a> {
+    rules: &'a RuleTable,
+    path_rules: &'a Vec<PathRuleEnum>,
+    text_rules: &'a Vec<TextRuleEnum>,
+    ast_entrypoints: &'a AstEntrypoints,
+    settings: &'a Settings,
+    /// Rules for which the user has accepted all fixes.
+    accepted_rules: FxHashSet<Rule>,
+    /// Set once the user has asked to stop.
+    quit: bool,
+}
+
+impl Reviewer<'_> {
+    /// Review the fixes for a single file, and write out the fixed file.
+    /// Returns the fixes applied, if any.
+    fn review_file(
+        &mut self,
+        path: &Path,
+        reader: &mut dyn BufRead,
+        writer: &mut dyn Write,
+    ) -> Result<Option<FixTable>> {
+        let source = read_to_string(path)
+            .with_context(|| format!("Error opening file {}", fs::relativize_path(path)))?;
+        let file =
+            SourceFileBuilder::new(path.to_string_lossy().as_ref(), source.as_str()).finish();
+
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_fortran::LANGUAGE.into())
+            .context("Error loading Fortran grammar")?;
+        let tree = parser
+            .parse(file.source_text(), None)
+            .context("Failed to parse")?;
+
+        if tree.root_node().has_error() {
+            warn_user_once_by_message!(
+                "Syntax errors detected in file: {}. No fixes will be applied.",
+                path.to_string_lossy()
+            );
+            return Ok(None);
+        }
+
+        let per_file_ignores = fs::ignores_from_path(path, &self.settings.check.per_file_ignores);
+        let required_applicability = self.settings.check.unsafe_fixes.required_applicability();
+
+        let diagnostics = check_path(
+            self.rules,
+            self.path_rules,
+            self.text_rules,
+            self.ast_entrypoints,
+            path,
+            &file,
+            self.settings,
+            &tree,
+            self.settings.check.ignore_allow_comments,
+        )
+        .into_iter()
+        .filter(|diagnostic| {
+            // Custom rules have no fixes, and aren't in the registry
+            diagnostic
+                .fix
+                .as_ref()
+                .is_some_and(|fix| fix.applies(required_applicability))
+                && !per_file_ignores.contains(&diagnostic.kind.rule())
+                && self.rules.should_fix(diagnostic.kind.rule())
+        })
+        .sorted_by_key(Ranged::start)
+        .collect_vec();
+
+        let mut accepted: Vec<&Diagnostic> = Vec::new();
+        for diagnostic in &diagnostics {
+            let rule = diagnostic.kind.rule();
+            if !self.accepted_rules.contains(&rule) {
+                let message = DiagnosticMessage::from_ruff(&file, diagnostic.clone());
+                TextEmitter::default()
+                    .with_show_fix_status(true)
+                    .with_show_fix_diff(true)
+                    .with_show_source(true)
+                    .with_unsafe_fixes(self.settings.check.unsafe_fixes)
+                    .emit(writer, &[message])?;
+
+                let choice = prompt(rule, reader, writer)?;
+                writeln!(writer)?;
+                match choice {
+                    Choice::Accept => {}
+                    Choice::Skip => continue,
+                    Choice::AcceptRule => {
+                        self.accepted_rules.insert(rule);
+                    }
+                    Choice::Quit => {
+                        self.quit = true;
+                        break;
+                    }
+                }
+            }
+            accepted.push(diagnostic);
+        }
+
+        if accepted.is_empty() {
+            return Ok(None);
+        }
+
+        let accepted_counts = accepted
+            .iter()
+            .counts_by(|diagnostic| diagnostic.kind.rule());
+        let locator = Locator::new(file.source_text());
+        let FixResult { code, fixes, .. } =
+            apply_fixes(accepted.into_iter(), &locator, file.name());
+
+        // Fixes that overlap an earlier fix can't be applied in the same pass
+        for (rule, count) in accepted_counts.into_iter().sorted() {
+            let skipped = count - fixes.get(&rule).copied().unwrap_or_default();
+            if skipped > 0 {
+                let noun = if skipped == 1 { "fix" } else { "fixes" };
+                let message = format!(
+                    "Skipped {skipped} accepted {} {noun} that overlapped other fixes. \
+                     Run again to review them.",
+                    rule.as_ref()
+                );
+                writeln!(writer, "{}", message.yellow().bold())?;
+            }
+        }
+
+        // Don't write out anything that no longer parses
+        let fixed_tree = parser
+            .parse(code.source_text(), None)
+            .context("Failed to parse")?;
+        if fixed_tree.root_node().has_error() {
+            report_fix_syntax_error(path, code.source_text(), fixes.keys().copied());
+            return Ok(None);
+        }
+
+        std::fs::write(path, code.source_text())?;
+        Ok(Some(fixes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use test_case::test_case;
+
+    use super::{prompt, Choice};
+    use crate::registry::Rule;
+
+    #[test_case("a\n", Choice::Accept; "accept")]
+    #[test_case("skip\n", Choice::Skip; "skip")]
+    #[test_case("l\n", Choice::AcceptRule; "accept rule")]
+    #[test_case("q\n", Choice::Quit; "quit")]
+    #[test_case("", Choice::Quit; "end of input")]
+    #[test_case("x\n\nS\ns\n", Choice::Skip; "invalid answers are asked again")]
+    fn answers(input: &str, expected: Choice) -> anyhow::Result<()> {
+        let mut reader = Cursor::new(input);
+        let mut output = Vec::new();
+        let choice = prompt(Rule::StarKind, &mut reader, &mut output)?;
+        assert_eq!(choice, expected);
+        Ok(())
+    }
+}
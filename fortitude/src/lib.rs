@@ -1,12 +1,17 @@
 mod allow_comments;
+mod api;
 mod ast;
 pub mod check;
 pub mod cli;
+pub mod config;
 mod configuration;
+pub mod custom_rules;
 mod diagnostics;
 pub mod explain;
 mod fix;
+pub mod fix_safety_table;
 mod fs;
+mod interactive;
 mod locator;
 pub mod logging;
 pub mod message;
@@ -14,6 +19,8 @@ pub mod options;
 pub mod options_base;
 mod printer;
 pub mod registry;
+pub mod rule;
+pub mod rule_presets;
 mod rule_redirects;
 mod rule_selector;
 pub mod rule_table;
@@ -25,8 +32,14 @@ pub mod stdin;
 #[cfg(test)]
 mod test;
 mod text_helpers;
+mod timing;
+mod watch;
+pub use crate::api::{
+    fix_source, lint_paths, lint_source, resolve_settings, FixedSource, SettingsBuilder,
+};
 pub use crate::registry::clap_completion::RuleParser;
-pub use crate::rule_selector::clap_completion::RuleSelectorParser;
+pub use crate::rule_selector::clap_completion::{BuiltinRuleSelectorParser, RuleSelectorParser};
+pub use crate::rule_selector::{ParseError, RuleSelector};
 
 use ruff_diagnostics::{Diagnostic, DiagnosticKind};
 use ruff_source_file::SourceFile;
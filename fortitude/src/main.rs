@@ -6,8 +6,10 @@ use clap::{CommandFactory, Parser};
 use colored::Colorize;
 use fortitude::check::check;
 use fortitude::cli::{Cli, SubCommands};
+use fortitude::config::config;
 use fortitude::explain::explain;
 use fortitude::logging::set_up_logging;
+use fortitude::rule::rule;
 
 fn main() -> Result<ExitCode> {
     let args = Cli::parse();
@@ -17,6 +19,8 @@ fn main() -> Result<ExitCode> {
     let status = match args.command {
         SubCommands::Check(check_args) => check(check_args, &args.global_options),
         SubCommands::Explain(args) => explain(args),
+        SubCommands::Rule(args) => rule(args),
+        SubCommands::Config(args) => config(args),
         SubCommands::GenerateShellCompletion { shell } => {
             shell.generate(&mut Cli::command(), &mut stdout());
             return Ok(ExitCode::SUCCESS);
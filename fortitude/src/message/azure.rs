@@ -29,9 +29,7 @@ impl Emitter for AzureEmitter {
                 filename = message.filename(),
                 line = location.row,
                 col = location.column,
-                code = message
-                    .rule()
-                    .map_or_else(String::new, |rule| format!("code={};", rule.noqa_code())),
+                code = format!("code={};", message.code()),
                 body = message.body(),
             )?;
         }
@@ -0,0 +1,76 @@
+use std::io::Write;
+
+use crate::fs::relativize_path;
+use crate::message::{escape_markup, group_messages_by_filename, Emitter, MessageWithLocation};
+
+use super::DiagnosticMessage;
+
+/// Generate XML with violations in the Checkstyle format, as understood by
+/// Jenkins and other CI tools.
+#[derive(Default)]
+pub struct CheckstyleEmitter;
+
+impl Emitter for CheckstyleEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        messages: &[DiagnosticMessage],
+    ) -> anyhow::Result<()> {
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(writer, r#"<checkstyle version="4.3">"#)?;
+
+        for (filename, messages) in group_messages_by_filename(messages) {
+            writeln!(
+                writer,
+                r#"  <file name="{}">"#,
+                escape_markup(&relativize_path(filename))
+            )?;
+
+            for message in messages {
+                let MessageWithLocation {
+                    message,
+                    start_location,
+                } = message;
+
+                let body = if message.fixable() {
+                    format!("{} (fix available)", message.body())
+                } else {
+                    message.body().to_string()
+                };
+
+                let source = format!("fortitude.{}.{}", message.code(), message.rule_name());
+
+                writeln!(
+                    writer,
+                    r#"    <error line="{row}" column="{col}" severity="error" message="{body}" source="{source}"/>"#,
+                    row = start_location.row,
+                    col = start_location.column,
+                    body = escape_markup(&body),
+                    source = escape_markup(&source),
+                )?;
+            }
+
+            writeln!(writer, "  </file>")?;
+        }
+
+        writeln!(writer, "</checkstyle>")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::message::tests::{capture_emitter_output, create_messages};
+    use crate::message::CheckstyleEmitter;
+
+    #[test]
+    fn output() {
+        let mut emitter = CheckstyleEmitter;
+        let content = capture_emitter_output(&mut emitter, &create_messages());
+
+        assert_snapshot!(content);
+    }
+}
@@ -29,7 +29,7 @@ impl Emitter for GithubEmitter {
             write!(
                 writer,
                 "::error title=Fortitude{code},file={file},line={row},col={column},endLine={end_row},endColumn={end_column}::",
-                code = message.rule().map_or_else(String::new, |rule| format!(" ({})", rule.noqa_code())),
+                code = format!(" ({})", message.code()),
                 file = message.filename(),
                 row = source_location.row,
                 column = source_location.column,
@@ -45,9 +45,7 @@ impl Emitter for GithubEmitter {
                 column = location.column,
             )?;
 
-            if let Some(rule) = message.rule() {
-                write!(writer, " {}", rule.noqa_code())?;
-            }
+            write!(writer, " {}", message.code())?;
 
             writeln!(writer, " {}", message.body())?;
         }
@@ -84,11 +84,7 @@ impl Serialize for SerializedMessages<'_> {
             }
             fingerprints.insert(message_fingerprint);
 
-            let description = if let Some(rule) = message.rule() {
-                format!("({}) {}", rule.noqa_code(), message.body())
-            } else {
-                message.body().to_string()
-            };
+            let description = format!("({}) {}", message.code(), message.body());
 
             let value = json!({
                 "description": description,
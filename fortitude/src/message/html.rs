@@ -0,0 +1,299 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use itertools::Itertools;
+use ruff_diagnostics::Applicability;
+use ruff_source_file::OneIndexed;
+use ruff_text_size::{Ranged, TextLen, TextRange};
+
+use crate::fs::relativize_path;
+use crate::message::{
+    escape_markup, group_messages_by_filename, Emitter, MessageWithLocation, RULE_DOCS_URL,
+};
+
+use super::DiagnosticMessage;
+
+const STYLE: &str = r#"body { font-family: sans-serif; margin: 2em auto; max-width: 60em; color: #222; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th, td { border: 1px solid #ccc; padding: 0.25em 0.75em; text-align: left; }
+th { background: #eee; }
+td.count { text-align: right; }
+section.file { margin-top: 2em; }
+div.diagnostic { border-left: 4px solid #c33; margin: 1em 0; padding-left: 1em; }
+p.location { font-family: monospace; font-weight: bold; }
+a.code { color: #c33; }
+pre.snippet { background: #f6f6f6; padding: 0.5em; overflow-x: auto; }
+pre.snippet mark { background: #fcc; }
+p.fix { color: #275; }
+p.help { color: #555; }"#;
+
+/// The number of lines of source to show either side of a diagnostic.
+const CONTEXT_LINES: usize = 2;
+
+/// Generate a self-contained HTML report, with summaries of violations per
+/// file and per rule.
+#[derive(Default)]
+pub struct HtmlEmitter;
+
+impl Emitter for HtmlEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        messages: &[DiagnosticMessage],
+    ) -> anyhow::Result<()> {
+        let grouped = group_messages_by_filename(messages);
+
+        writeln!(writer, "<!DOCTYPE html>")?;
+        writeln!(writer, r#"<html lang="en">"#)?;
+        writeln!(writer, "<head>")?;
+        writeln!(writer, r#"<meta charset="utf-8">"#)?;
+        writeln!(writer, "<title>Fortitude report</title>")?;
+        writeln!(writer, "<style>\n{STYLE}\n</style>")?;
+        writeln!(writer, "</head>")?;
+        writeln!(writer, "<body>")?;
+        writeln!(writer, "<h1>Fortitude report</h1>")?;
+
+        if messages.is_empty() {
+            writeln!(writer, "<p>All checks passed!</p>")?;
+            writeln!(writer, "</body>")?;
+            writeln!(writer, "</html>")?;
+            return Ok(());
+        }
+
+        let files = grouped.len();
+        writeln!(
+            writer,
+            "<p>Found {} error{} in {files} file{}.</p>",
+            messages.len(),
+            if messages.len() == 1 { "" } else { "s" },
+            if files == 1 { "" } else { "s" },
+        )?;
+
+        write_rule_summary(writer, messages)?;
+        write_file_summary(writer, &grouped)?;
+
+        writeln!(writer, "<h2>Diagnostics</h2>")?;
+        for (index, (filename, messages)) in grouped.iter().enumerate() {
+            writeln!(writer, r#"<section class="file" id="file-{index}">"#)?;
+            writeln!(
+                writer,
+                "<h3>{}</h3>",
+                escape_markup(&relativize_path(filename))
+            )?;
+            for message in messages {
+                write_message(writer, filename, message)?;
+            }
+            writeln!(writer, "</section>")?;
+        }
+
+        writeln!(writer, "</body>")?;
+        writeln!(writer, "</html>")?;
+
+        Ok(())
+    }
+}
+
+/// Write a table of the number of violations of each rule, most common first.
+fn write_rule_summary(
+    writer: &mut dyn Write,
+    messages: &[DiagnosticMessage],
+) -> anyhow::Result<()> {
+    let mut counts: BTreeMap<&str, (&DiagnosticMessage, usize)> = BTreeMap::new();
+    for message in messages {
+        counts
+            .entry(message.code.as_str())
+            .or_insert((message, 0))
+            .1 += 1;
+    }
+
+    writeln!(writer, "<h2>Summary by rule</h2>")?;
+    writeln!(writer, "<table>")?;
+    writeln!(writer, "<tr><th>Code</th><th>Rule</th><th>Count</th></tr>")?;
+    for (code, (message, count)) in counts
+        .into_iter()
+        .sorted_by(|(a_code, (_, a)), (b_code, (_, b))| b.cmp(a).then(a_code.cmp(b_code)))
+    {
+        writeln!(
+            writer,
+            r#"<tr><td>{code}</td><td>{name}</td><td class="count">{count}</td></tr>"#,
+            code = rule_link(message, code),
+            name = escape_markup(message.rule_name()),
+        )?;
+    }
+    writeln!(writer, "</table>")?;
+    Ok(())
+}
+
+/// Write a table of the number of violations in each file.
+fn write_file_summary(
+    writer: &mut dyn Write,
+    grouped: &BTreeMap<&str, Vec<MessageWithLocation>>,
+) -> anyhow::Result<()> {
+    writeln!(writer, "<h2>Summary by file</h2>")?;
+    writeln!(writer, "<table>")?;
+    writeln!(writer, "<tr><th>File</th><th>Count</th></tr>")?;
+    for (index, (filename, messages)) in grouped.iter().enumerate() {
+        writeln!(
+            writer,
+            r##"<tr><td><a href="#file-{index}">{path}</a></td><td class="count">{count}</td></tr>"##,
+            path = escape_markup(&relativize_path(filename)),
+            count = messages.len(),
+        )?;
+    }
+    writeln!(writer, "</table>")?;
+    Ok(())
+}
+
+/// Write a single diagnostic, with its location, code snippet and any fix.
+fn write_message(
+    writer: &mut dyn Write,
+    filename: &str,
+    message: &MessageWithLocation,
+) -> anyhow::Result<()> {
+    writeln!(writer, r#"<div class="diagnostic">"#)?;
+    writeln!(
+        writer,
+        r#"<p class="location">{path}:{row}:{col}: {code} {body}</p>"#,
+        path = escape_markup(&relativize_path(filename)),
+        row = message.start_location.row,
+        col = message.start_location.column,
+        code = rule_link(message, &message.code),
+        body = escape_markup(message.body()),
+    )?;
+
+    // The `0..0` range is used to highlight file-level diagnostics.
+    if message.range() != TextRange::default() {
+        writeln!(
+            writer,
+            r#"<pre class="snippet">{}</pre>"#,
+            highlighted_snippet(message)
+        )?;
+    }
+
+    if let Some(fix) = message.fix() {
+        let applicability = match fix.applicability() {
+            Applicability::Safe => "Safe fix available",
+            Applicability::Unsafe => "Unsafe fix available",
+            Applicability::DisplayOnly => "Display-only fix available",
+        };
+        match message.suggestion() {
+            Some(suggestion) => writeln!(
+                writer,
+                r#"<p class="fix">{applicability}: {}</p>"#,
+                escape_markup(suggestion)
+            )?,
+            None => writeln!(writer, r#"<p class="fix">{applicability}</p>"#)?,
+        }
+    } else if let Some(suggestion) = message.suggestion() {
+        writeln!(
+            writer,
+            r#"<p class="help">Help: {}</p>"#,
+            escape_markup(suggestion)
+        )?;
+    }
+
+    writeln!(writer, "</div>")?;
+    Ok(())
+}
+
+/// Render the source lines around a message as HTML, with line numbers,
+/// marking up the range of the source that the message applies to.
+fn highlighted_snippet(message: &MessageWithLocation) -> String {
+    let source_code = message.source_file().to_source_code();
+    let range = message.range();
+
+    let content_start_index = source_code.line_index(range.start());
+    let mut start_index = content_start_index.saturating_sub(CONTEXT_LINES);
+    while start_index < content_start_index && source_code.line_text(start_index).trim().is_empty()
+    {
+        start_index = start_index.saturating_add(1);
+    }
+
+    let content_end_index = source_code.line_index(range.end());
+    let mut end_index = content_end_index
+        .saturating_add(CONTEXT_LINES)
+        .min(OneIndexed::from_zero_indexed(source_code.line_count()));
+    while end_index > content_end_index && source_code.line_text(end_index).trim().is_empty() {
+        end_index = end_index.saturating_sub(1);
+    }
+
+    let width = end_index.get().to_string().len();
+    (start_index.get()..=end_index.get())
+        .map(|row| {
+            let line = OneIndexed::from_zero_indexed(row - 1);
+            let line_start = source_code.line_start(line);
+            let text = source_code
+                .slice(TextRange::new(line_start, source_code.line_end(line)))
+                .trim_end_matches(['\n', '\r']);
+            let line_range = TextRange::at(line_start, text.text_len());
+            let html = match range.intersect(line_range) {
+                Some(marked) if !marked.is_empty() => mark_range(text, marked - line_start),
+                _ => escape_markup(text),
+            };
+            if html.is_empty() {
+                format!("{row:>width$} |")
+            } else {
+                format!("{row:>width$} | {html}")
+            }
+        })
+        .join("\n")
+}
+
+/// Escape `text`, wrapping the bytes in `range` in `<mark>`
+fn mark_range(text: &str, range: TextRange) -> String {
+    format!(
+        "{}<mark>{}</mark>{}",
+        escape_markup(&text[TextRange::up_to(range.start())]),
+        escape_markup(&text[range]),
+        escape_markup(&text[usize::from(range.end())..])
+    )
+}
+
+/// A link to the documentation for the rule that was violated.
+fn rule_link(message: &DiagnosticMessage, code: &str) -> String {
+    match message.rule() {
+        Some(rule) => format!(
+            r#"<a class="code" href="{RULE_DOCS_URL}/{name}/">{code}</a>"#,
+            name = rule.as_ref(),
+            code = escape_markup(code),
+        ),
+        None => escape_markup(code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+    use ruff_text_size::{TextRange, TextSize};
+
+    use super::mark_range;
+    use crate::message::tests::{capture_emitter_output, create_messages};
+    use crate::message::HtmlEmitter;
+
+    #[test]
+    fn output() {
+        let mut emitter = HtmlEmitter;
+        let content = capture_emitter_output(&mut emitter, &create_messages());
+
+        assert_snapshot!(content);
+    }
+
+    #[test]
+    fn mark_range_escapes_text() {
+        assert_eq!(
+            mark_range(
+                " a < b",
+                TextRange::new(TextSize::from(1), TextSize::from(4))
+            ),
+            " <mark>a &lt;</mark> b".to_string()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let mut emitter = HtmlEmitter;
+        let content = capture_emitter_output(&mut emitter, &[]);
+
+        assert_snapshot!(content);
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::Write;
+
+use itertools::Itertools;
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::fs::relativize_path;
+use crate::message::text::MessageCodeFrame;
+use crate::message::{group_messages_by_filename, Emitter, MessageWithLocation, RULE_DOCS_URL};
+
+use super::DiagnosticMessage;
+
+/// Space reserved at the end of truncated output for the note saying how much
+/// was left out.
+const TRUNCATION_NOTE_LENGTH: usize = 100;
+
+/// Generate a compact Markdown summary, suitable for posting as a comment on
+/// a pull request.
+#[derive(Default)]
+pub struct MarkdownEmitter {
+    max_length: Option<usize>,
+}
+
+impl MarkdownEmitter {
+    /// Limit the output to approximately this many bytes, leaving out files,
+    /// and if necessary rows of the summary table, that don't fit.
+    #[must_use]
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+}
+
+impl Emitter for MarkdownEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        messages: &[DiagnosticMessage],
+    ) -> anyhow::Result<()> {
+        let mut output = String::new();
+        writeln!(output, "## Fortitude")?;
+        writeln!(output)?;
+
+        if messages.is_empty() {
+            writeln!(output, "All checks passed!")?;
+            write!(writer, "{output}")?;
+            return Ok(());
+        }
+
+        let grouped = group_messages_by_filename(messages);
+        writeln!(
+            output,
+            "Found {} error{} in {} file{}.",
+            messages.len(),
+            if messages.len() == 1 { "" } else { "s" },
+            grouped.len(),
+            if grouped.len() == 1 { "" } else { "s" },
+        )?;
+        writeln!(output)?;
+
+        writeln!(output, "| Code | Rule | Count |")?;
+        writeln!(output, "| ---- | ---- | ----: |")?;
+        let mut omitted_rules = 0;
+        for row in rule_table_rows(messages)? {
+            if omitted_rules == 0 && self.fits(&output, &row) {
+                output.push_str(&row);
+            } else {
+                omitted_rules += 1;
+            }
+        }
+
+        let mut omitted_files = 0;
+        let mut omitted_messages = 0;
+        for (filename, messages) in &grouped {
+            let section = file_section(filename, messages)?;
+            if omitted_rules == 0 && omitted_files == 0 && self.fits(&output, &section) {
+                output.push_str(&section);
+            } else {
+                omitted_files += 1;
+                omitted_messages += messages.len();
+            }
+        }
+
+        if omitted_files > 0 {
+            writeln!(output)?;
+            let rules = if omitted_rules > 0 {
+                format!(
+                    "{omitted_rules} more rule{} and ",
+                    if omitted_rules == 1 { "" } else { "s" }
+                )
+            } else {
+                String::new()
+            };
+            writeln!(
+                output,
+                "_Output truncated: {rules}{omitted_messages} more error{} in {omitted_files} file{} not shown._",
+                if omitted_messages == 1 { "" } else { "s" },
+                if omitted_files == 1 { "" } else { "s" },
+            )?;
+        }
+
+        write!(writer, "{output}")?;
+        Ok(())
+    }
+}
+
+impl MarkdownEmitter {
+    /// Whether `extra` can be added to `output` while leaving room for the
+    /// truncation note.
+    fn fits(&self, output: &str, extra: &str) -> bool {
+        self.max_length.map_or(true, |max_length| {
+            output.len() + extra.len() + TRUNCATION_NOTE_LENGTH <= max_length
+        })
+    }
+}
+
+/// The rows of a table of the number of violations of each rule, most common
+/// first.
+fn rule_table_rows(messages: &[DiagnosticMessage]) -> anyhow::Result<Vec<String>> {
+    let mut counts: BTreeMap<&str, (&DiagnosticMessage, usize)> = BTreeMap::new();
+    for message in messages {
+        counts
+            .entry(message.code.as_str())
+            .or_insert((message, 0))
+            .1 += 1;
+    }
+
+    let mut rows = Vec::with_capacity(counts.len());
+    for (code, (message, count)) in counts
+        .into_iter()
+        .sorted_by(|(a_code, (_, a)), (b_code, (_, b))| b.cmp(a).then(a_code.cmp(b_code)))
+    {
+        let mut row = String::new();
+        match message.rule() {
+            Some(rule) => writeln!(
+                row,
+                "| [{code}]({RULE_DOCS_URL}/{name}/) | {name} | {count} |",
+                name = rule.as_ref(),
+            )?,
+            None => writeln!(row, "| {code} | {} | {count} |", message.name())?,
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// A collapsible section listing the violations in a single file.
+fn file_section(filename: &str, messages: &[MessageWithLocation]) -> anyhow::Result<String> {
+    let mut section = String::new();
+    let path = relativize_path(filename);
+
+    writeln!(section)?;
+    writeln!(section, "<details>")?;
+    writeln!(
+        section,
+        "<summary><code>{path}</code> ({} error{})</summary>",
+        messages.len(),
+        if messages.len() == 1 { "" } else { "s" },
+    )?;
+
+    for message in messages {
+        writeln!(section)?;
+        write!(
+            section,
+            "**{row}:{col}** `{code}` {body}",
+            row = message.start_location.row,
+            col = message.start_location.column,
+            code = message.code,
+            body = escape_markdown(message.body()),
+        )?;
+        if message.fixable() {
+            section.push_str(" (fix available)");
+        }
+        writeln!(section)?;
+
+        // The `0..0` range is used to highlight file-level diagnostics.
+        if message.range() != TextRange::default() {
+            let frame = MessageCodeFrame {
+                message: message.message,
+            }
+            .render(false);
+            let fence = code_fence(&frame);
+            writeln!(section)?;
+            writeln!(section, "{fence}")?;
+            writeln!(section, "{}", frame.trim_end())?;
+            writeln!(section, "{fence}")?;
+        }
+    }
+
+    writeln!(section)?;
+    writeln!(section, "</details>")?;
+    Ok(section)
+}
+
+/// A fence for a code block containing `text`, longer than any run of
+/// backticks in the text so that the block isn't closed early.
+fn code_fence(text: &str) -> String {
+    let longest_run = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    "`".repeat(longest_run.max(2) + 1)
+}
+
+/// Escape characters that would otherwise be interpreted as Markdown formatting.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::code_fence;
+    use crate::message::tests::{capture_emitter_output, create_messages};
+    use crate::message::MarkdownEmitter;
+
+    #[test]
+    fn output() {
+        let mut emitter = MarkdownEmitter::default();
+        let content = capture_emitter_output(&mut emitter, &create_messages());
+
+        assert_snapshot!(content);
+    }
+
+    #[test]
+    fn truncated() {
+        let mut emitter = MarkdownEmitter::default().with_max_length(Some(800));
+        let content = capture_emitter_output(&mut emitter, &create_messages());
+
+        assert_snapshot!(content);
+    }
+
+    #[test]
+    fn truncated_table() {
+        let mut emitter = MarkdownEmitter::default().with_max_length(Some(300));
+        let content = capture_emitter_output(&mut emitter, &create_messages());
+
+        assert_snapshot!(content);
+    }
+
+    #[test]
+    fn fence_longer_than_backticks_in_source() {
+        assert_eq!(code_fence("print *, 'x'"), "```");
+        assert_eq!(code_fence("! ``` not a fence"), "````");
+        assert_eq!(code_fence("! `````"), "``````");
+    }
+}
@@ -1,26 +1,34 @@
 pub use azure::AzureEmitter;
+pub use checkstyle::CheckstyleEmitter;
 pub use github::GithubEmitter;
 pub use gitlab::GitlabEmitter;
 pub use grouped::GroupedEmitter;
+pub use html::HtmlEmitter;
 pub use json::JsonEmitter;
 pub use json_lines::JsonLinesEmitter;
 pub use junit::JunitEmitter;
+pub use markdown::MarkdownEmitter;
 pub use pylint::PylintEmitter;
 pub use rdjson::RdjsonEmitter;
 pub use sarif::SarifEmitter;
+pub use sonar::SonarEmitter;
 pub use text::TextEmitter;
 
 mod azure;
+mod checkstyle;
 mod diff;
 mod github;
 mod gitlab;
 mod grouped;
+mod html;
 mod json;
 mod json_lines;
 mod junit;
+mod markdown;
 mod pylint;
 mod rdjson;
 mod sarif;
+mod sonar;
 mod text;
 
 use std::collections::BTreeMap;
@@ -33,7 +41,7 @@ use ruff_source_file::{SourceFile, SourceFileBuilder, SourceLocation};
 use ruff_text_size::{Ranged, TextRange};
 
 /// Reports of each violation. They are pretty-printable and sortable.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DiagnosticMessage {
     kind: DiagnosticKind,
     range: TextRange,
@@ -57,6 +65,18 @@ impl DiagnosticMessage {
         }
     }
 
+    /// Create a message for a violation of a user-defined rule, which isn't
+    /// in the registry and so has its code looked up separately.
+    pub(crate) fn from_custom_rule(file: &SourceFile, diagnostic: Diagnostic, code: &str) -> Self {
+        Self {
+            kind: diagnostic.kind,
+            file: file.clone(),
+            code: code.to_string(),
+            range: diagnostic.range,
+            fix: diagnostic.fix,
+        }
+    }
+
     pub fn from_error<S: AsRef<str>>(filename: S, diagnostic: Diagnostic) -> Self {
         let code = diagnostic.kind.rule().noqa_code().to_string();
         Self {
@@ -73,6 +93,20 @@ impl DiagnosticMessage {
         &self.kind.name
     }
 
+    /// Returns the code of the rule that was violated, such as `C001`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the kebab-case name of the rule that was violated, such as
+    /// `implicit-typing`. This is the name used in allow comments.
+    pub fn rule_name(&self) -> &str {
+        match self.rule() {
+            Some(rule) => rule.as_ref(),
+            None => &self.kind.name,
+        }
+    }
+
     /// Returns the message body to display to the user.
     pub fn body(&self) -> &str {
         &self.kind.body
@@ -94,10 +128,15 @@ impl DiagnosticMessage {
         self.fix().is_some()
     }
 
-    // TODO (peter): Don't return `Option`?
-    /// Returns the [`Rule`] corresponding to the diagnostic message.
+    /// Removes the [`Fix`] from the message, if there is any.
+    pub(crate) fn remove_fix(&mut self) {
+        self.fix = None;
+    }
+
+    /// Returns the [`Rule`] corresponding to the diagnostic message, or
+    /// `None` if it was raised by a custom rule.
     pub fn rule(&self) -> Option<Rule> {
-        Some(self.kind.rule())
+        Rule::from_violation_name(&self.kind.name)
     }
 
     /// Returns the filename for the message.
@@ -185,6 +224,25 @@ fn group_messages_by_filename(
     grouped_messages
 }
 
+/// Base URL for the online rule documentation.
+const RULE_DOCS_URL: &str = "https://fortitude.readthedocs.io/en/stable/rules";
+
+/// Escape text for inclusion in HTML or XML.
+fn escape_markup(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use ruff_diagnostics::{Diagnostic, DiagnosticKind, Edit, Fix};
@@ -248,6 +306,35 @@ end module
         ]
     }
 
+    /// Messages for a file that couldn't be read, and one that couldn't be
+    /// parsed
+    pub(super) fn create_error_messages() -> Vec<DiagnosticMessage> {
+        let io_error = Diagnostic::new(
+            DiagnosticKind {
+                name: "IoError".to_string(),
+                body: "Permission denied (os error 13)".to_string(),
+                suggestion: None,
+            },
+            TextRange::default(),
+        );
+
+        let syntax_error = Diagnostic::new(
+            DiagnosticKind {
+                name: "SyntaxError".to_string(),
+                body: "Syntax error".to_string(),
+                suggestion: None,
+            },
+            TextRange::new(TextSize::from(8), TextSize::from(11)),
+        );
+
+        let syntax_source = SourceFileBuilder::new("syntax.f90", "program (x)\nend\n").finish();
+
+        vec![
+            DiagnosticMessage::from_error("unreadable.f90", io_error),
+            DiagnosticMessage::from_ruff(&syntax_source, syntax_error),
+        ]
+    }
+
     pub(super) fn capture_emitter_output(
         emitter: &mut dyn Emitter,
         messages: &[DiagnosticMessage],
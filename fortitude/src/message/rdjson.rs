@@ -75,7 +75,7 @@ fn message_to_rdjson_value(message: &DiagnosticMessage) -> Value {
                 "range": rdjson_range(&start_location, &end_location),
             },
             "code": {
-                "value": message.rule().map(|rule| rule.noqa_code().to_string()),
+                "value": message.code(),
                 // "url": message.rule().and_then(|rule| rule.url()),
             },
             "suggestions": rdjson_suggestions(fix.edits(), &source_code),
@@ -88,7 +88,7 @@ fn message_to_rdjson_value(message: &DiagnosticMessage) -> Value {
                 "range": rdjson_range(&start_location, &end_location),
             },
             "code": {
-                "value": message.rule().map(|rule| rule.noqa_code().to_string()),
+                "value": message.code(),
                 // "url": message.rule().and_then(|rule| rule.url()),
             },
         })
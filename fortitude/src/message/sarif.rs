@@ -2,10 +2,10 @@
 // Copyright 2022 Charles Marsh
 // SPDX-License-Identifier: MIT
 
-use std::collections::HashSet;
 use std::io::Write;
 
 use anyhow::Result;
+use itertools::Itertools;
 use serde::{Serialize, Serializer};
 use serde_json::json;
 
@@ -28,8 +28,11 @@ impl Emitter for SarifEmitter {
             .map(SarifResult::from_message)
             .collect::<Result<Vec<_>>>()?;
 
-        let unique_rules: HashSet<_> = results.iter().filter_map(|result| result.rule).collect();
-        let mut rules: Vec<SarifRule> = unique_rules.into_iter().map(SarifRule::from).collect();
+        let mut rules: Vec<SarifRule> = messages
+            .iter()
+            .unique_by(|message| message.code())
+            .map(SarifRule::from_message)
+            .collect();
         rules.sort_by(|a, b| a.code.cmp(&b.code));
 
         let output = json!({
@@ -77,6 +80,22 @@ impl From<Rule> for SarifRule<'_> {
     }
 }
 
+impl<'a> SarifRule<'a> {
+    fn from_message(message: &'a DiagnosticMessage) -> Self {
+        match message.rule() {
+            Some(rule) => Self::from(rule),
+            // Custom rules aren't in the registry, so only have a name and code
+            None => Self {
+                name: message.rule_name(),
+                code: message.code().to_string(),
+                linter: "custom",
+                summary: message.rule_name(),
+                explanation: None,
+            },
+        }
+    }
+}
+
 impl Serialize for SarifRule<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -107,7 +126,7 @@ impl Serialize for SarifRule<'_> {
 
 #[derive(Debug)]
 struct SarifResult {
-    rule: Option<Rule>,
+    code: String,
     level: String,
     message: String,
     uri: String,
@@ -124,7 +143,7 @@ impl SarifResult {
         let end_location = message.compute_end_location();
         let path = normalize_path(message.filename());
         Ok(Self {
-            rule: message.rule(),
+            code: message.code().to_string(),
             level: "error".to_string(),
             message: message.body().to_string(),
             uri: url::Url::from_file_path(&path)
@@ -144,7 +163,7 @@ impl SarifResult {
         let end_location = message.compute_end_location();
         let path = normalize_path(message.filename());
         Ok(Self {
-            rule: message.rule(),
+            code: message.code().to_string(),
             level: "error".to_string(),
             message: message.body().to_string(),
             uri: path.display().to_string(),
@@ -179,7 +198,7 @@ impl Serialize for SarifResult {
                     }
                 }
             }],
-            "ruleId": self.rule.map(|rule| rule.noqa_code().to_string()),
+            "ruleId": self.code,
         })
         .serialize(serializer)
     }
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use ruff_text_size::{Ranged, TextRange};
+use serde_json::{json, Value};
+
+use crate::fs::relativize_path;
+use crate::message::{Emitter, RULE_DOCS_URL};
+use crate::registry::{Category, Rule};
+
+use super::DiagnosticMessage;
+
+/// Rule under which I/O and syntax errors are reported. These aren't problems
+/// with the code itself, so are kept separate from the lint rules.
+const ERROR_RULE_ID: &str = "fortitude-error";
+
+/// Generate JSON with violations in the SonarQube generic external issue format.
+//  https://docs.sonarsource.com/sonarqube/latest/analyzing-source-code/importing-external-issues/generic-issue-import-format/
+#[derive(Default)]
+pub struct SonarEmitter;
+
+impl Emitter for SonarEmitter {
+    fn emit(
+        &mut self,
+        writer: &mut dyn Write,
+        messages: &[DiagnosticMessage],
+    ) -> anyhow::Result<()> {
+        let mut rules = BTreeMap::new();
+        let mut issues = Vec::with_capacity(messages.len());
+
+        for message in messages {
+            let code = message.code().to_string();
+            let is_error = matches!(Category::parse_code(&code), Some((Category::Error, _)));
+            let rule_id = if is_error {
+                ERROR_RULE_ID.to_string()
+            } else {
+                code.clone()
+            };
+            rules
+                .entry(rule_id.clone())
+                .or_insert_with(|| match message.rule() {
+                    _ if is_error => error_rule_to_json(),
+                    Some(rule) => rule_to_json(rule, &code),
+                    None => custom_rule_to_json(message.rule_name(), &code),
+                });
+
+            let body = if is_error {
+                format!("{code} {}", message.body())
+            } else if message.fixable() {
+                format!("{} (fix available)", message.body())
+            } else {
+                message.body().to_string()
+            };
+
+            let mut location = json!({
+                "message": body,
+                "filePath": relativize_path(message.filename()),
+            });
+            // The `0..0` range is used for file-level diagnostics, including
+            // files that couldn't be read, which Sonar reports on the file as a
+            // whole when there's no range
+            if message.range() != TextRange::default() {
+                let start_location = message.compute_start_location();
+                let end_location = message.compute_end_location();
+                location["textRange"] = json!({
+                    "startLine": start_location.row,
+                    "endLine": end_location.row,
+                    // Sonar columns are zero-indexed
+                    "startColumn": start_location.column.to_zero_indexed(),
+                    "endColumn": end_location.column.to_zero_indexed(),
+                });
+            }
+
+            issues.push(json!({
+                "ruleId": rule_id,
+                "primaryLocation": location,
+            }));
+        }
+
+        let report = json!({
+            "rules": rules.into_values().collect::<Vec<_>>(),
+            "issues": issues,
+        });
+
+        serde_json::to_writer_pretty(writer, &report)?;
+
+        Ok(())
+    }
+}
+
+/// Describe a rule in terms of Sonar's clean code attributes and software
+/// qualities, based on its category.
+fn rule_to_json(rule: Rule, code: &str) -> Value {
+    let name = rule.as_ref();
+    let (attribute, quality, severity) = match Category::parse_code(code) {
+        Some((Category::Error, _)) => ("LOGICAL", "RELIABILITY", "HIGH"),
+        Some((Category::Correctness, _)) => ("LOGICAL", "RELIABILITY", "MEDIUM"),
+        Some((Category::Portability, _)) => ("CONVENTIONAL", "MAINTAINABILITY", "MEDIUM"),
+        Some((Category::Style, _)) => ("FORMATTED", "MAINTAINABILITY", "LOW"),
+        _ => ("CONVENTIONAL", "MAINTAINABILITY", "LOW"),
+    };
+    json!({
+        "id": code,
+        "name": name,
+        "description": format!("See {RULE_DOCS_URL}/{name}/"),
+        "engineId": "fortitude",
+        "cleanCodeAttribute": attribute,
+        "impacts": [
+            {
+                "softwareQuality": quality,
+                "severity": severity,
+            }
+        ]
+    })
+}
+
+/// I/O and syntax errors, which all share one rule
+fn error_rule_to_json() -> Value {
+    json!({
+        "id": ERROR_RULE_ID,
+        "name": "error",
+        "description": "Fortitude was unable to read or parse the file",
+        "engineId": "fortitude",
+        "cleanCodeAttribute": "LOGICAL",
+        "impacts": [
+            {
+                "softwareQuality": "RELIABILITY",
+                "severity": "HIGH",
+            }
+        ]
+    })
+}
+
+/// Custom rules have no documentation, and aren't in any category.
+fn custom_rule_to_json(name: &str, code: &str) -> Value {
+    json!({
+        "id": code,
+        "name": name,
+        "description": format!("Custom rule `{name}`"),
+        "engineId": "fortitude",
+        "cleanCodeAttribute": "CONVENTIONAL",
+        "impacts": [
+            {
+                "softwareQuality": "MAINTAINABILITY",
+                "severity": "MEDIUM",
+            }
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::message::tests::{capture_emitter_output, create_error_messages, create_messages};
+    use crate::message::SonarEmitter;
+
+    #[test]
+    fn output() {
+        let mut emitter = SonarEmitter;
+        let content = capture_emitter_output(&mut emitter, &create_messages());
+
+        assert_snapshot!(content);
+    }
+
+    #[test]
+    fn errors() {
+        let mut emitter = SonarEmitter;
+        let content = capture_emitter_output(&mut emitter, &create_error_messages());
+
+        assert_snapshot!(content);
+    }
+}
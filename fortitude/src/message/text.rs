@@ -127,9 +127,7 @@ impl Display for RuleCodeAndBody<'_> {
             if let Some(fix) = self.message.fix() {
                 // Do not display an indicator for inapplicable fixes
                 if fix.applies(self.unsafe_fixes.required_applicability()) {
-                    if let Some(rule) = self.message.rule() {
-                        write!(f, "{} ", rule.noqa_code().to_string().red().bold())?;
-                    }
+                    write!(f, "{} ", self.message.code().red().bold())?;
                     return write!(
                         f,
                         "{fix}{body}",
@@ -140,16 +138,12 @@ impl Display for RuleCodeAndBody<'_> {
             }
         };
 
-        if let Some(rule) = self.message.rule() {
-            write!(
-                f,
-                "{code} {body}",
-                code = rule.noqa_code().to_string().red().bold(),
-                body = self.message.body(),
-            )
-        } else {
-            f.write_str(self.message.body())
-        }
+        write!(
+            f,
+            "{code} {body}",
+            code = self.message.code().red().bold(),
+            body = self.message.body(),
+        )
     }
 }
 
@@ -157,8 +151,9 @@ pub(super) struct MessageCodeFrame<'a> {
     pub(crate) message: &'a DiagnosticMessage,
 }
 
-impl Display for MessageCodeFrame<'_> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl MessageCodeFrame<'_> {
+    /// Render the code frame, optionally with terminal colours.
+    pub(crate) fn render(&self, styled: bool) -> String {
         let source_code = self.message.source_file().to_source_code();
 
         let content_start_index = source_code.line_index(self.message.start());
@@ -202,9 +197,7 @@ impl Display for MessageCodeFrame<'_> {
             .sum();
 
         let mut code = self.message.code.bold().bright_red();
-
-        // Disable colours for tests, if the user requests it via env var, or non-tty
-        if cfg!(test) || !colored::control::SHOULD_COLORIZE.should_colorize() {
+        if !styled {
             code = code.clear();
         };
 
@@ -222,14 +215,20 @@ impl Display for MessageCodeFrame<'_> {
             snippet
         };
 
-        // Disable colours for tests, if the user requests it via env var, or non-tty
-        let renderer = if !cfg!(test) && colored::control::SHOULD_COLORIZE.should_colorize() {
+        let renderer = if styled {
             Renderer::styled()
         } else {
             Renderer::plain()
         };
-        let source_block = renderer.render(snippet_with_footer);
-        writeln!(f, "{source_block}")
+        renderer.render(snippet_with_footer).to_string()
+    }
+}
+
+impl Display for MessageCodeFrame<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Disable colours for tests, if the user requests it via env var, or non-tty
+        let styled = !cfg!(test) && colored::control::SHOULD_COLORIZE.should_colorize();
+        writeln!(f, "{}", self.render(styled))
     }
 }
 
@@ -9,7 +9,13 @@ use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    rule_presets::Preset,
     rule_selector::RuleSelector,
+    rules::correctness::settings::{
+        AllocateStatSettings, FloatingPointEqualitySettings, PrecisionSettings,
+    },
+    rules::modernisation::settings::DoublePrecisionSettings,
+    rules::portability::settings::UnapprovedRealKindSettings,
     settings::{OutputFormat, ProgressBar},
 };
 
@@ -77,10 +83,13 @@ pub struct CheckOptions {
     /// (shows source), `"concise"`, `"grouped"` (group messages by file), `"json"`
     /// (machine-readable), `"junit"` (machine-readable XML), `"github"` (GitHub
     /// Actions annotations), `"gitlab"` (GitLab CI code quality report),
-    /// `"pylint"` (Pylint text format) or `"azure"` (Azure Pipeline logging commands).
+    /// `"pylint"` (Pylint text format), `"azure"` (Azure Pipeline logging commands),
+    /// `"html"` (self-contained HTML report), `"checkstyle"` (Checkstyle XML),
+    /// `"sonar"` (SonarQube generic issue import format) or `"markdown"`
+    /// (summary for pull request comments).
     #[option(
         default = r#""full""#,
-        value_type = r#""full" | "concise" | "grouped" | "json" | "junit" | "github" | "gitlab" | "pylint" | "azure""#,
+        value_type = r#""full" | "concise" | "grouped" | "json" | "junit" | "github" | "gitlab" | "pylint" | "azure" | "html" | "checkstyle" | "sonar" | "markdown""#,
         example = r#"
             # Group violations by containing file.
             output-format = "grouped"
@@ -88,6 +97,21 @@ pub struct CheckOptions {
     )]
     pub output_format: Option<OutputFormat>,
 
+    /// The maximum length, in bytes, of the output when using
+    /// `output-format = "markdown"`, including the summary table. Files whose
+    /// violations don't fit, and if necessary rows of the summary table, are
+    /// left out, and a note is added saying how many were omitted. This is
+    /// useful to keep pull request comments within the limits of the forge.
+    #[option(
+        default = "null",
+        value_type = "int",
+        example = r#"
+            # GitHub limits comments to 65536 characters
+            markdown-max-length = 65000
+        "#
+    )]
+    pub markdown_max_length: Option<usize>,
+
     /// Whether to enable preview mode. When preview mode is enabled, Fortitude will
     /// use unstable rules, fixes, and formatting.
     #[option(
@@ -159,6 +183,84 @@ pub struct CheckOptions {
     )]
     pub extend_select: Option<Vec<RuleSelector>>,
 
+    /// A named collection of rules to use as the base set of enabled rules.
+    /// One of:
+    ///
+    /// - `"strict"`: every rule, including those that are turned off by default;
+    /// - `"legacy"`: only errors and likely bugs, for large or old codebases;
+    /// - `"modern"`: the default rules, plus all obsolescent, modernisation
+    ///   and portability rules.
+    ///
+    /// If [`select`](#check_select) is also set, it takes precedence. Presets
+    /// can also be selected as part of `select` or `extend-select` using the
+    /// `preset:` prefix, for example `select = ["preset:modern"]`.
+    #[option(
+        default = "null",
+        value_type = r#""strict" | "legacy" | "modern""#,
+        example = r#"
+            # Start from the legacy preset, and also enable style rules
+            preset = "legacy"
+            extend-select = ["S"]
+        "#
+    )]
+    pub preset: Option<Preset>,
+
+    /// A list of rule codes or prefixes to consider fixable. By default,
+    /// all rules are considered fixable.
+    #[option(
+        default = r#"["ALL"]"#,
+        value_type = "list[RuleSelector]",
+        example = r#"
+            # Only allow fix behavior for `superfluous-implicit-none` (`S201`) and style rules.
+            fixable = ["S201", "S"]
+        "#
+    )]
+    pub fixable: Option<Vec<RuleSelector>>,
+
+    /// A list of rule codes or prefixes to consider non-fixable. Violations
+    /// of these rules are still reported, but their fixes are never applied.
+    ///
+    /// When breaking ties between fixable and non-fixable rules (via `fixable`
+    /// and `unfixable`, respectively), more specific prefixes override less
+    /// specific prefixes.
+    #[option(
+        default = "[]",
+        value_type = "list[RuleSelector]",
+        example = r#"
+            # Disable fix for `superfluous-implicit-none` (`S201`).
+            unfixable = ["S201"]
+        "#
+    )]
+    pub unfixable: Option<Vec<RuleSelector>>,
+
+    /// A list of rule codes or prefixes for which unsafe fixes should be considered
+    /// safe.
+    #[option(
+        default = "[]",
+        value_type = "list[RuleSelector]",
+        example = r#"
+            # Allow applying the unsafe fix for `implicit-external-procedures` (`C003`) without `--unsafe-fixes`.
+            extend-safe-fixes = ["C003"]
+        "#
+    )]
+    pub extend_safe_fixes: Option<Vec<RuleSelector>>,
+
+    /// A list of rule codes or prefixes for which safe fixes should be considered
+    /// unsafe.
+    ///
+    /// If a rule is selected by both `extend-safe-fixes` and `extend-unsafe-fixes`,
+    /// the more specific selector takes precedence. If they are equally specific,
+    /// the fix is considered unsafe.
+    #[option(
+        default = "[]",
+        value_type = "list[RuleSelector]",
+        example = r#"
+            # Require `--unsafe-fixes` to apply the fix for `superfluous-implicit-none` (`S201`).
+            extend-unsafe-fixes = ["S201"]
+        "#
+    )]
+    pub extend_unsafe_fixes: Option<Vec<RuleSelector>>,
+
     // File resolver options
     /// A list of file extensions to check
     #[option(
@@ -279,4 +381,230 @@ pub struct CheckOptions {
         "#
     )]
     pub per_file_ignores: Option<FxHashMap<String, Vec<RuleSelector>>>,
+
+    /// A list of user-defined rules. Each rule reports a violation for every
+    /// match of a [tree-sitter query](https://tree-sitter.github.io/tree-sitter/using-parsers/queries/)
+    /// against the [tree-sitter-fortran](https://github.com/stadelmanma/tree-sitter-fortran)
+    /// grammar, and has:
+    ///
+    /// - `name`: a unique name in kebab-case, like `no-mpi-abort`;
+    /// - `code`: a unique code made of uppercase letters followed by digits,
+    ///   like `HOUSE001`. The letters can't be the prefix of one of
+    ///   Fortitude's own categories;
+    /// - `message`: the message to report. `{capture}` is replaced by the text
+    ///   of that capture in the query;
+    /// - `query`: the tree-sitter query. Violations are reported on the
+    ///   `@violation` capture, or the first capture if there isn't one;
+    /// - `predicates` (optional): a mapping from capture names to regular
+    ///   expressions that the text of each capture must match. Matching is
+    ///   case-insensitive.
+    ///
+    /// Custom rules are enabled by default, unless [`select`](#check_select)
+    /// is given. They can be selected, ignored, and used in allow comments
+    /// and [`per-file-ignores`](#check_per-file-ignores) by their exact code,
+    /// and allow comments can also use their name.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            custom-rules = [
+              { name = "no-mpi-abort", code = "HOUSE001", message = "Use `fatal_error` instead of `{function}`", query = "(subroutine_call (identifier) @function) @violation", predicates = { function = "^mpi_abort$" } },
+            ]
+        "#
+    )]
+    pub custom_rules: Option<Vec<CustomRuleOptions>>,
+
+    /// Options for the `double-precision` rule
+    #[option_group]
+    pub double_precision: Option<DoublePrecisionOptions>,
+
+    /// Options for the `unapproved-real-kind` rule
+    #[option_group]
+    pub unapproved_real_kind: Option<UnapprovedRealKindOptions>,
+
+    /// Options for the `floating-point-equality` rule
+    #[option_group]
+    pub floating_point_equality: Option<FloatingPointEqualityOptions>,
+
+    /// Options for the `mixed-precision-arithmetic` and `narrowing-assignment`
+    /// rules
+    #[option_group]
+    pub precision: Option<PrecisionOptions>,
+
+    /// Options for the `missing-allocate-stat` and `unchecked-allocate-stat`
+    /// rules
+    #[option_group]
+    pub allocate_stat: Option<AllocateStatOptions>,
+}
+
+/// A user-defined rule, given as part of [`CheckOptions::custom_rules`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CustomRuleOptions {
+    pub name: String,
+    pub code: String,
+    pub message: String,
+    pub query: String,
+    pub predicates: Option<FxHashMap<String, String>>,
+}
+
+/// Options for the `double-precision` rule (`MOD001`).
+#[derive(Clone, Debug, PartialEq, Eq, Default, OptionsMetadata, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct DoublePrecisionOptions {
+    /// The kind parameter that fixes use in place of `double precision`, such
+    /// as `dp` or `wp` for projects that define their own kinds.
+    #[option(
+        default = r#""real64""#,
+        value_type = "str",
+        example = r#"
+            kind = "dp"
+        "#
+    )]
+    pub kind: Option<String>,
+
+    /// The module that fixes import [`kind`](#check_double-precision_kind)
+    /// from.
+    #[option(
+        default = r#""iso_fortran_env""#,
+        value_type = "str",
+        example = r#"
+            kind-module = "precision"
+        "#
+    )]
+    pub kind_module: Option<String>,
+}
+
+impl DoublePrecisionOptions {
+    pub fn into_settings(self) -> DoublePrecisionSettings {
+        let default = DoublePrecisionSettings::default();
+        DoublePrecisionSettings {
+            kind: self.kind.unwrap_or(default.kind),
+            kind_module: self.kind_module.unwrap_or(default.kind_module),
+        }
+    }
+}
+
+/// Options for the `unapproved-real-kind` rule (`PORT031`).
+#[derive(Clone, Debug, PartialEq, Eq, Default, OptionsMetadata, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct UnapprovedRealKindOptions {
+    /// The named constants that may be used as the kind of `real` and
+    /// `complex` variables and literals, such as a project-wide working
+    /// precision `wp`. Fixes replace other kinds with the first of these.
+    #[option(
+        default = r#"["wp"]"#,
+        value_type = "list[str]",
+        example = r#"
+            real-kinds = ["wp", "sp"]
+        "#
+    )]
+    pub real_kinds: Option<Vec<String>>,
+
+    /// The module that the [`real-kinds`](#check_unapproved-real-kind_real-kinds)
+    /// are imported from, which is needed to fix violations.
+    #[option(
+        default = "null",
+        value_type = "str",
+        example = r#"
+            kind-module = "precision_mod"
+        "#
+    )]
+    pub kind_module: Option<String>,
+}
+
+impl UnapprovedRealKindOptions {
+    pub fn into_settings(self) -> UnapprovedRealKindSettings {
+        let default = UnapprovedRealKindSettings::default();
+        UnapprovedRealKindSettings {
+            real_kinds: self.real_kinds.unwrap_or(default.real_kinds),
+            kind_module: self.kind_module.or(default.kind_module),
+        }
+    }
+}
+
+/// Options for the `floating-point-equality` rule (`C151`).
+#[derive(Clone, Debug, PartialEq, Eq, Default, OptionsMetadata, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct FloatingPointEqualityOptions {
+    /// Whether to allow comparisons against an exact zero, such as
+    /// `x == 0.0_wp`, which are often used deliberately to guard against
+    /// dividing by zero.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            allow-zero = true
+        "#
+    )]
+    pub allow_zero: Option<bool>,
+}
+
+impl FloatingPointEqualityOptions {
+    pub fn into_settings(self) -> FloatingPointEqualitySettings {
+        let default = FloatingPointEqualitySettings::default();
+        FloatingPointEqualitySettings {
+            allow_zero: self.allow_zero.unwrap_or(default.allow_zero),
+        }
+    }
+}
+
+/// Options for the `mixed-precision-arithmetic` (`C171`) and
+/// `narrowing-assignment` (`C172`) rules.
+#[derive(Clone, Debug, PartialEq, Eq, Default, OptionsMetadata, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PrecisionOptions {
+    /// A mapping from the names of kind parameters to their precision in
+    /// bits. The kinds from `iso_fortran_env`, such as `real64`, and
+    /// `iso_c_binding`, such as `c_double`, are always known. Expressions
+    /// using any other kinds are skipped.
+    #[option(
+        default = r#"{ sp = 32, dp = 64, qp = 128 }"#,
+        value_type = "dict[str, int]",
+        example = r#"
+            kinds = { wp = 64, sp = 32 }
+        "#
+    )]
+    pub kinds: Option<FxHashMap<String, u16>>,
+}
+
+impl PrecisionOptions {
+    pub fn into_settings(self) -> PrecisionSettings {
+        match self.kinds {
+            Some(kinds) => PrecisionSettings {
+                kinds: kinds
+                    .into_iter()
+                    .map(|(name, bits)| (name.to_lowercase(), bits))
+                    .collect(),
+            },
+            None => PrecisionSettings::default(),
+        }
+    }
+}
+
+/// Options for the `missing-allocate-stat` (`C181`) and
+/// `unchecked-allocate-stat` (`C182`) rules.
+#[derive(Clone, Debug, PartialEq, Eq, Default, OptionsMetadata, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AllocateStatOptions {
+    /// Whether to allow `allocate` and `deallocate` statements without a
+    /// checked `stat=` in main programs, where stopping on failure is often
+    /// acceptable, and only check them in modules and procedures.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            exempt-programs = true
+        "#
+    )]
+    pub exempt_programs: Option<bool>,
+}
+
+impl AllocateStatOptions {
+    pub fn into_settings(self) -> AllocateStatSettings {
+        let default = AllocateStatSettings::default();
+        AllocateStatSettings {
+            exempt_programs: self.exempt_programs.unwrap_or(default.exempt_programs),
+        }
+    }
 }
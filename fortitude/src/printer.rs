@@ -1,5 +1,4 @@
 use std::cmp::Reverse;
-use std::fmt::Display;
 use std::io::Write;
 
 use anyhow::Result;
@@ -13,11 +12,10 @@ use crate::diagnostics::{Diagnostics, FixMap};
 use crate::fs::relativize_path;
 use crate::logging::LogLevel;
 use crate::message::{
-    AzureEmitter, DiagnosticMessage, Emitter, GithubEmitter, GitlabEmitter, GroupedEmitter,
-    JsonEmitter, JsonLinesEmitter, JunitEmitter, PylintEmitter, RdjsonEmitter, SarifEmitter,
-    TextEmitter,
+    AzureEmitter, CheckstyleEmitter, DiagnosticMessage, Emitter, GithubEmitter, GitlabEmitter,
+    GroupedEmitter, HtmlEmitter, JsonEmitter, JsonLinesEmitter, JunitEmitter, MarkdownEmitter,
+    PylintEmitter, RdjsonEmitter, SarifEmitter, SonarEmitter, TextEmitter,
 };
-use crate::rules::Rule;
 use crate::settings::{FixMode, OutputFormat, UnsafeFixes};
 
 bitflags! {
@@ -33,72 +31,20 @@ bitflags! {
 }
 
 #[derive(Serialize)]
-struct ExpandedStatistics {
-    code: Option<SerializeRuleAsCode>,
-    name: SerializeRuleAsTitle,
+struct ExpandedStatistics<'a> {
+    code: &'a str,
+    name: &'a str,
     count: usize,
     fixable: bool,
 }
 
-#[derive(Copy, Clone)]
-struct SerializeRuleAsCode(Rule);
-
-impl Serialize for SerializeRuleAsCode {
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_str(&self.0.noqa_code().to_string())
-    }
-}
-
-impl Display for SerializeRuleAsCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0.noqa_code())
-    }
-}
-
-impl From<Rule> for SerializeRuleAsCode {
-    fn from(rule: Rule) -> Self {
-        Self(rule)
-    }
-}
-
-struct SerializeRuleAsTitle(Rule);
-
-impl Serialize for SerializeRuleAsTitle {
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_str(self.0.as_ref())
-    }
-}
-
-impl Display for SerializeRuleAsTitle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(self.0.as_ref())
-    }
-}
-
-impl From<Option<Rule>> for SerializeRuleAsTitle {
-    fn from(rule: Option<Rule>) -> Self {
-        match rule {
-            Some(rule) => Self(rule),
-            // This is a bit weird, but it's because `DiagnosticMessage::rule`
-            // returns `Option<Rule>`, leftover from ruff which treats
-            // `SyntaxError` specially and we don't. Should just not return `Option` there
-            None => Self(Rule::SyntaxError),
-        }
-    }
-}
-
 pub(crate) struct Printer {
     format: OutputFormat,
     log_level: LogLevel,
     flags: Flags,
     fix_mode: FixMode,
     unsafe_fixes: UnsafeFixes,
+    markdown_max_length: Option<usize>,
 }
 
 impl Printer {
@@ -108,6 +54,7 @@ impl Printer {
         flags: Flags,
         fix_mode: FixMode,
         unsafe_fixes: UnsafeFixes,
+        markdown_max_length: Option<usize>,
     ) -> Self {
         Self {
             format,
@@ -115,6 +62,7 @@ impl Printer {
             flags,
             fix_mode,
             unsafe_fixes,
+            markdown_max_length,
         }
     }
 
@@ -213,6 +161,15 @@ impl Printer {
             }
         }
 
+        let withheld = results.diagnostics.fixes_withheld;
+        if withheld > 0 {
+            let es = if withheld == 1 { "" } else { "es" };
+            writeln!(
+                writer,
+                "{withheld} fix{es} withheld by the `fixable` and `unfixable` settings."
+            )?;
+        }
+
         Ok(())
     }
 
@@ -284,6 +241,20 @@ impl Printer {
             OutputFormat::Pylint => {
                 PylintEmitter.emit(writer, &results.diagnostics.messages)?;
             }
+            OutputFormat::Html => {
+                HtmlEmitter.emit(writer, &results.diagnostics.messages)?;
+            }
+            OutputFormat::Checkstyle => {
+                CheckstyleEmitter.emit(writer, &results.diagnostics.messages)?;
+            }
+            OutputFormat::Sonar => {
+                SonarEmitter.emit(writer, &results.diagnostics.messages)?;
+            }
+            OutputFormat::Markdown => {
+                MarkdownEmitter::default()
+                    .with_max_length(self.markdown_max_length)
+                    .emit(writer, &results.diagnostics.messages)?;
+            }
         }
 
         writer.flush()?;
@@ -298,12 +269,12 @@ impl Printer {
         let statistics: Vec<ExpandedStatistics> = diagnostics
             .messages
             .iter()
-            .sorted_by_key(|message| (message.rule(), message.fixable()))
+            .sorted_by_key(|message| (message.rule(), message.code(), message.fixable()))
             .fold(
                 vec![],
                 |mut acc: Vec<(&DiagnosticMessage, usize)>, message| {
                     if let Some((prev_message, count)) = acc.last_mut() {
-                        if prev_message.rule() == message.rule() {
+                        if prev_message.code() == message.code() {
                             *count += 1;
                             return acc;
                         }
@@ -314,8 +285,8 @@ impl Printer {
             )
             .iter()
             .map(|&(message, count)| ExpandedStatistics {
-                code: message.rule().map(std::convert::Into::into),
-                name: message.rule().into(),
+                code: message.code(),
+                name: message.rule_name(),
                 count,
                 fixable: message.fixable(),
             })
@@ -339,12 +310,7 @@ impl Printer {
                 );
                 let code_width = statistics
                     .iter()
-                    .map(|statistic| {
-                        statistic
-                            .code
-                            .map_or_else(String::new, |rule| rule.to_string())
-                            .len()
-                    })
+                    .map(|statistic| statistic.code.len())
                     .max()
                     .unwrap();
                 let any_fixable = statistics.iter().any(|statistic| statistic.fixable);
@@ -358,11 +324,7 @@ impl Printer {
                         writer,
                         "{:>count_width$}\t{:<code_width$}\t{}{}",
                         statistic.count.to_string().bold(),
-                        statistic
-                            .code
-                            .map_or_else(String::new, |rule| rule.to_string())
-                            .red()
-                            .bold(),
+                        statistic.code.red().bold(),
                         if any_fixable {
                             if statistic.fixable {
                                 &fixable
@@ -0,0 +1,134 @@
+use std::collections::BTreeSet;
+use std::io::{stdout, Write};
+use std::process::ExitCode;
+
+use anyhow::Result;
+use itertools::Itertools;
+use ruff_diagnostics::FixAvailability;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use crate::cli::{HelpFormat, RuleArgs};
+use crate::registry::{Category, RuleNamespace};
+use crate::rule_redirects::get_redirects_to;
+use crate::rules::{Rule, RuleGroup};
+
+/// Summary of a single rule, as shown by `fortitude rule`.
+#[derive(Debug, Serialize)]
+struct RuleInfo {
+    code: String,
+    name: &'static str,
+    category: String,
+    status: &'static str,
+    default: bool,
+    fix: &'static str,
+    redirected_from: Vec<&'static str>,
+}
+
+impl RuleInfo {
+    fn new(rule: Rule) -> Self {
+        let code = rule.noqa_code().to_string();
+        let category = Category::parse_code(&code)
+            .map(|(category, _)| category.to_string())
+            .unwrap_or_default();
+        let status = match rule.group() {
+            RuleGroup::Stable => "stable",
+            RuleGroup::Preview => "preview",
+            RuleGroup::Deprecated => "deprecated",
+            RuleGroup::Removed => "removed",
+        };
+        let fix = match rule.fixable() {
+            FixAvailability::Always => "always",
+            FixAvailability::Sometimes => "sometimes",
+            FixAvailability::None => "none",
+        };
+        let redirected_from = get_redirects_to(&code);
+        Self {
+            code,
+            name: rule.as_ref(),
+            category,
+            status,
+            default: rule.is_default(),
+            fix,
+            redirected_from,
+        }
+    }
+
+    fn selection(&self) -> &'static str {
+        if self.default {
+            "default"
+        } else {
+            "optional"
+        }
+    }
+}
+
+/// List rules along with their metadata. If no rules are given, lists all rules.
+pub fn rule(args: RuleArgs) -> Result<ExitCode> {
+    let rules: BTreeSet<Rule> = if args.rules.is_empty() {
+        Rule::iter().collect()
+    } else {
+        args.rules
+            .iter()
+            .flat_map(|selector| selector.all_rules())
+            .collect()
+    };
+
+    let infos = rules
+        .into_iter()
+        .map(RuleInfo::new)
+        .sorted_by(|a, b| a.code.cmp(&b.code))
+        .collect_vec();
+
+    let mut stdout = stdout().lock();
+    match args.output_format {
+        HelpFormat::Text => write_text(&mut stdout, &infos)?,
+        HelpFormat::Json => writeln!(stdout, "{}", serde_json::to_string_pretty(&infos)?)?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Write rules as a plain text table, one rule per line.
+fn write_text(writer: &mut dyn Write, infos: &[RuleInfo]) -> Result<()> {
+    let header = [
+        "code",
+        "name",
+        "category",
+        "status",
+        "selection",
+        "fix",
+        "redirected from",
+    ];
+    let rows = infos
+        .iter()
+        .map(|info| {
+            [
+                info.code.clone(),
+                info.name.to_string(),
+                info.category.clone(),
+                info.status.to_string(),
+                info.selection().to_string(),
+                info.fix.to_string(),
+                info.redirected_from.join(", "),
+            ]
+        })
+        .collect_vec();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let header = header.map(str::to_string);
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .join("  ");
+        writeln!(writer, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
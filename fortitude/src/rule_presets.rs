@@ -0,0 +1,129 @@
+//! Named collections of rules, to make it easier to pick a sensible
+//! starting point for a project.
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::registry::{Category, Rule, RuleNamespace};
+#[cfg(any(feature = "test-rules", test))]
+use crate::rules::testing::test_rules::TEST_RULES;
+
+/// The prefix used to select a preset, as in `select = ["preset:strict"]`.
+pub const PRESET_PREFIX: &str = "preset:";
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    strum_macros::AsRefStr,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Preset {
+    /// Every rule, including those that are turned off by default.
+    Strict,
+    /// A minimal set of rules for large or old codebases, only reporting
+    /// errors and likely bugs.
+    Legacy,
+    /// The default rules, plus all rules that encourage moving away from
+    /// obsolescent and non-portable features.
+    Modern,
+}
+
+impl Preset {
+    /// Returns `true` if the given rule is part of this preset. As with
+    /// categories, this doesn't consider whether the rule is in preview, or
+    /// has been deprecated or removed.
+    pub fn includes(&self, rule: Rule) -> bool {
+        #[cfg(any(feature = "test-rules", test))]
+        if TEST_RULES.contains(&rule) {
+            return false;
+        }
+
+        let Some((category, _)) = Category::parse_code(rule.noqa_code().prefix()) else {
+            return false;
+        };
+
+        match self {
+            Preset::Strict => true,
+            Preset::Legacy => {
+                rule.is_default()
+                    && matches!(
+                        category,
+                        Category::Error | Category::Correctness | Category::Fortitude
+                    )
+            }
+            Preset::Modern => {
+                rule.is_default()
+                    || matches!(
+                        category,
+                        Category::Obsolescent | Category::Modernisation | Category::Portability
+                    )
+            }
+        }
+    }
+
+    /// Returns all rules in this preset.
+    pub fn rules(&self) -> impl Iterator<Item = Rule> + '_ {
+        Rule::iter().filter(|rule| self.includes(*rule))
+    }
+
+    /// Returns a short description of the preset.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Preset::Strict => "Every rule, including those that are turned off by default",
+            Preset::Legacy => "Only errors and likely bugs, for large or old codebases",
+            Preset::Modern => {
+                "The default rules, plus all obsolescent, modernisation and portability rules"
+            }
+        }
+    }
+
+    /// Returns the presets that include the given rule.
+    pub fn containing(rule: Rule) -> Vec<Preset> {
+        Preset::iter()
+            .filter(|preset| preset.includes(rule))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn preset_from_str() {
+        assert_eq!(Preset::from_str("strict"), Ok(Preset::Strict));
+        assert_eq!(Preset::from_str("legacy"), Ok(Preset::Legacy));
+        assert_eq!(Preset::from_str("modern"), Ok(Preset::Modern));
+        assert!(Preset::from_str("lenient").is_err());
+    }
+
+    #[test]
+    fn preset_contents() {
+        assert!(Preset::Strict.includes(Rule::SuperfluousImplicitNone));
+        assert!(!Preset::Modern.includes(Rule::SuperfluousImplicitNone));
+        assert!(!Preset::Legacy.includes(Rule::SuperfluousImplicitNone));
+
+        assert!(Preset::Modern.includes(Rule::DoublePrecision));
+        assert!(!Preset::Legacy.includes(Rule::DoublePrecision));
+
+        assert!(Preset::Legacy.includes(Rule::ImplicitTyping));
+        assert!(!Preset::Legacy.includes(Rule::LineTooLong));
+
+        for preset in Preset::iter() {
+            assert!(!preset.includes(Rule::StableTestRule));
+        }
+    }
+}
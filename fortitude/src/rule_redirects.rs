@@ -18,6 +18,17 @@ pub(crate) fn get_redirect(code: &str) -> Option<(&'static str, &'static str)> {
     REDIRECTS.get_key_value(code).map(|(k, v)| (*k, *v))
 }
 
+/// Returns all old codes that redirect to the given code, in sorted order.
+pub(crate) fn get_redirects_to(code: &str) -> Vec<&'static str> {
+    let mut redirects = REDIRECTS
+        .iter()
+        .filter(|(_, &target)| target == code)
+        .map(|(&source, _)| source)
+        .collect::<Vec<_>>();
+    redirects.sort_unstable();
+    redirects
+}
+
 static REDIRECTS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
     HashMap::from_iter([
         // Deprecated categories
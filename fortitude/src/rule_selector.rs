@@ -14,7 +14,9 @@ use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+use crate::custom_rules::is_custom_code;
 use crate::registry::{Category, Rule, RuleNamespace, RuleSet};
+use crate::rule_presets::{Preset, PRESET_PREFIX};
 use crate::rule_redirects::{get_deprecated_category, get_redirect};
 use crate::rules::{RuleCodePrefix, RuleGroup, RuleIter};
 use crate::settings::{PatternPrefixPair, PreviewMode};
@@ -42,6 +44,10 @@ pub enum RuleSelector {
         redirected_to: Vec<RuleCodePrefix>,
         redirected_from: String,
     },
+    /// Select all rules in a named preset.
+    Preset(Preset),
+    /// Select a user-defined rule by its exact code.
+    Custom(String),
 }
 
 impl From<Category> for RuleSelector {
@@ -71,6 +77,7 @@ impl FromStr for RuleSelector {
         // **Changes should be reflected in `parse_no_redirect` as well**
         match s {
             "ALL" => Ok(Self::All),
+            _ if s.starts_with(PRESET_PREFIX) => parse_preset(s),
             _ => {
                 let (s, redirected_from) = match get_redirect(s) {
                     Some((from, target)) => (target, Some(from)),
@@ -99,6 +106,10 @@ impl FromStr for RuleSelector {
                     });
                 }
 
+                if is_custom_code(s) {
+                    return Ok(Self::Custom(s.to_string()));
+                }
+
                 let (category, code) =
                     Category::parse_code(s).ok_or_else(|| ParseError::Unknown(s.to_string()))?;
 
@@ -125,6 +136,14 @@ impl FromStr for RuleSelector {
     }
 }
 
+/// Parse a selector of the form `preset:<name>`.
+fn parse_preset(s: &str) -> Result<RuleSelector, ParseError> {
+    s.strip_prefix(PRESET_PREFIX)
+        .and_then(|name| Preset::from_str(name).ok())
+        .map(RuleSelector::Preset)
+        .ok_or_else(|| ParseError::Unknown(s.to_string()))
+}
+
 /// Returns `true` if the [`RuleCodePrefix`] matches a single rule exactly
 /// (e.g., `E225`, as opposed to `E2`).
 pub(crate) fn is_single_rule_selector(prefix: &RuleCodePrefix) -> bool {
@@ -162,6 +181,8 @@ impl RuleSelector {
             RuleSelector::DeprecatedCategory {
                 redirected_from, ..
             } => (redirected_from.to_string(), "".to_string()),
+            RuleSelector::Preset(preset) => (PRESET_PREFIX.to_string(), preset.to_string()),
+            RuleSelector::Custom(code) => ("".to_string(), code.to_string()),
         }
     }
 }
@@ -225,6 +246,11 @@ impl RuleSelector {
                     .collect::<Vec<_>>()
                     .into_iter(),
             ),
+            RuleSelector::Preset(preset) => {
+                RuleSelectorIter::Vec(preset.rules().collect::<Vec<_>>().into_iter())
+            }
+            // Custom rules aren't in the registry, and are selected separately
+            RuleSelector::Custom(..) => RuleSelectorIter::Vec(Vec::new().into_iter()),
         }
     }
 
@@ -241,10 +267,10 @@ impl RuleSelector {
                 RuleGroup::Preview => {
                     preview_enabled && (self.is_exact() || !preview_require_explicit)
                 }
-                // Deprecated rules are excluded in preview mode and with 'All' option unless explicitly selected
+                // Deprecated rules are excluded in preview mode and with 'All' or preset options unless explicitly selected
                 RuleGroup::Deprecated => {
                     (!preview_enabled || self.is_exact())
-                        && !matches!(self, RuleSelector::All { .. })
+                        && !matches!(self, RuleSelector::All | RuleSelector::Preset(..))
                 }
                 // Removed rules are included if explicitly selected but will error downstream
                 RuleGroup::Removed => self.is_exact(),
@@ -286,8 +312,9 @@ impl RuleSelector {
     pub fn specificity(&self) -> Specificity {
         match self {
             RuleSelector::All => Specificity::All,
+            RuleSelector::Preset(..) => Specificity::Preset,
             RuleSelector::Category(..) => Specificity::Category,
-            RuleSelector::Rule { .. } => Specificity::Rule,
+            RuleSelector::Rule { .. } | RuleSelector::Custom(..) => Specificity::Rule,
             RuleSelector::Prefix { prefix, .. } => {
                 let prefix: &'static str = prefix.short_code();
                 match prefix.len() {
@@ -323,7 +350,12 @@ impl RuleSelector {
         // **Changes should be reflected in `from_str` as well**
         match s {
             "ALL" => Ok(Self::All),
+            _ if s.starts_with(PRESET_PREFIX) => parse_preset(s),
             _ => {
+                if is_custom_code(s) {
+                    return Ok(Self::Custom(s.to_string()));
+                }
+
                 let (category, code) =
                     Category::parse_code(s).ok_or_else(|| ParseError::Unknown(s.to_string()))?;
 
@@ -354,6 +386,8 @@ impl RuleSelector {
 pub enum Specificity {
     /// The specificity when selecting all rules (e.g., `--select ALL`).
     All,
+    /// The specificity when selecting a preset (e.g., `--select preset:strict`).
+    Preset,
     /// The specificity when selecting a category (e.g., `--select PLE` or `--select UP`).
     Category,
     /// The specificity when selecting via a rule prefix with a one-character code (e.g., `--select PLE1`).
@@ -374,6 +408,7 @@ pub mod clap_completion {
 
     use crate::{
         registry::{Category, RuleNamespace},
+        rule_presets::{Preset, PRESET_PREFIX},
         rule_selector::is_single_rule_selector,
         rule_selector::RuleSelector,
         rules::RuleCodePrefix,
@@ -403,57 +438,90 @@ pub mod clap_completion {
                 .to_str()
                 .ok_or_else(|| clap::Error::new(clap::error::ErrorKind::InvalidUtf8))?;
 
-            value.parse().map_err(|_| {
-                let mut error =
-                    clap::Error::new(clap::error::ErrorKind::ValueValidation).with_cmd(cmd);
-                if let Some(arg) = arg {
-                    error.insert(
-                        clap::error::ContextKind::InvalidArg,
-                        clap::error::ContextValue::String(arg.to_string()),
-                    );
-                }
-                error.insert(
-                    clap::error::ContextKind::InvalidValue,
-                    clap::error::ContextValue::String(value.to_string()),
-                );
-                error
-            })
+            value.parse().map_err(|_| invalid_value(cmd, arg, value))
         }
 
         fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
             Some(Box::new(
-                std::iter::once(PossibleValue::new("ALL").help("all rules")).chain(
-                    Category::iter()
-                        .filter_map(|l| {
-                            let prefix = l.common_prefix();
-                            (!prefix.is_empty()).then(|| PossibleValue::new(prefix).help(l.name()))
-                        })
-                        .chain(RuleCodePrefix::iter().filter_map(|prefix| {
-                            // Ex) `UP`
-                            if prefix.short_code().is_empty() {
-                                let code = prefix.category().common_prefix();
-                                let name = prefix.category().name();
-                                return Some(PossibleValue::new(code).help(name));
-                            }
-
-                            // Ex) `UP004`
-                            if is_single_rule_selector(&prefix) {
-                                let rule = prefix.rules().next()?;
-                                let code = format!(
-                                    "{}{}",
-                                    prefix.category().common_prefix(),
-                                    prefix.short_code()
-                                );
-                                let name: &'static str = rule.into();
-                                return Some(PossibleValue::new(code).help(name));
-                            }
-
-                            None
-                        })),
-                ),
+                std::iter::once(PossibleValue::new("ALL").help("all rules"))
+                    .chain(Preset::iter().map(|preset| {
+                        PossibleValue::new(format!("{PRESET_PREFIX}{preset}"))
+                            .help(preset.description())
+                    }))
+                    .chain(
+                        Category::iter()
+                            .filter_map(|l| {
+                                let prefix = l.common_prefix();
+                                (!prefix.is_empty())
+                                    .then(|| PossibleValue::new(prefix).help(l.name()))
+                            })
+                            .chain(RuleCodePrefix::iter().filter_map(|prefix| {
+                                // Ex) `UP`
+                                if prefix.short_code().is_empty() {
+                                    let code = prefix.category().common_prefix();
+                                    let name = prefix.category().name();
+                                    return Some(PossibleValue::new(code).help(name));
+                                }
+
+                                // Ex) `UP004`
+                                if is_single_rule_selector(&prefix) {
+                                    let rule = prefix.rules().next()?;
+                                    let code = format!(
+                                        "{}{}",
+                                        prefix.category().common_prefix(),
+                                        prefix.short_code()
+                                    );
+                                    let name: &'static str = rule.into();
+                                    return Some(PossibleValue::new(code).help(name));
+                                }
+
+                                None
+                            })),
+                    ),
             ))
         }
     }
+
+    /// Parses selectors for built-in rules only, for commands such as
+    /// `explain` that don't read the configuration, and so don't know about
+    /// any custom rules.
+    #[derive(Clone)]
+    pub struct BuiltinRuleSelectorParser;
+
+    impl TypedValueParser for BuiltinRuleSelectorParser {
+        type Value = RuleSelector;
+
+        fn parse_ref(
+            &self,
+            cmd: &clap::Command,
+            arg: Option<&clap::Arg>,
+            value: &std::ffi::OsStr,
+        ) -> Result<Self::Value, clap::Error> {
+            match RuleSelectorParser.parse_ref(cmd, arg, value)? {
+                RuleSelector::Custom(code) => Err(invalid_value(cmd, arg, &code)),
+                selector => Ok(selector),
+            }
+        }
+
+        fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+            RuleSelectorParser.possible_values()
+        }
+    }
+
+    fn invalid_value(cmd: &clap::Command, arg: Option<&clap::Arg>, value: &str) -> clap::Error {
+        let mut error = clap::Error::new(clap::error::ErrorKind::ValueValidation).with_cmd(cmd);
+        if let Some(arg) = arg {
+            error.insert(
+                clap::error::ContextKind::InvalidArg,
+                clap::error::ContextValue::String(arg.to_string()),
+            );
+        }
+        error.insert(
+            clap::error::ContextKind::InvalidValue,
+            clap::error::ContextValue::String(value.to_string()),
+        );
+        error
+    }
 }
 
 #[derive(Debug)]
@@ -462,6 +530,7 @@ pub struct PerFileIgnore {
     absolute: PathBuf,
     negated: bool,
     rules: RuleSet,
+    custom_rules: Vec<String>,
 }
 
 impl PerFileIgnore {
@@ -473,6 +542,13 @@ impl PerFileIgnore {
         // Rules in preview are included here even if preview mode is disabled; it's
         // safe to ignore disabled rules
         let rules: RuleSet = prefixes.iter().flat_map(RuleSelector::all_rules).collect();
+        let custom_rules = prefixes
+            .iter()
+            .filter_map(|selector| match selector {
+                RuleSelector::Custom(code) => Some(code.clone()),
+                _ => None,
+            })
+            .collect();
         let negated = pattern.starts_with('!');
         if negated {
             pattern.drain(..1);
@@ -488,6 +564,7 @@ impl PerFileIgnore {
             absolute,
             negated,
             rules,
+            custom_rules,
         }
     }
 }
@@ -513,6 +590,8 @@ pub struct CompiledPerFileIgnore {
     pub basename_matcher: GlobMatcher,
     pub negated: bool,
     pub rules: RuleSet,
+    /// Codes of custom rules to ignore.
+    pub custom_rules: Vec<String>,
 }
 
 impl Display for CompiledPerFileIgnore {
@@ -524,6 +603,7 @@ impl Display for CompiledPerFileIgnore {
                 self.basename_matcher | globmatcher,
                 self.negated,
                 self.rules,
+                self.custom_rules | array,
             ]
         }
         Ok(())
@@ -554,6 +634,7 @@ impl CompiledPerFileIgnoreList {
                     basename_matcher,
                     negated: per_file_ignore.negated,
                     rules: per_file_ignore.rules,
+                    custom_rules: per_file_ignore.custom_rules,
                 })
             })
             .collect();
@@ -0,0 +1,143 @@
+use crate::ast::FortitudeNode;
+use crate::rules::utilities::{is_status_checked, keyword_value};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// Is `node` in a main program, rather than a module or external procedure
+fn in_program(node: &Node) -> bool {
+    node.ancestors()
+        .any(|ancestor| ancestor.kind() == "program")
+}
+
+/// Get the `allocate` or `deallocate` keyword of a statement
+fn statement_name(node: &Node) -> &'static str {
+    match node.kind() {
+        "allocate_statement" => "allocate",
+        _ => "deallocate",
+    }
+}
+
+/// ## What it does
+/// Checks for `allocate` and `deallocate` statements without a `stat=`
+/// argument.
+///
+/// ## Why is this bad?
+/// If an `allocate` or `deallocate` statement fails without `stat=`, the
+/// program stops immediately, usually with no indication of what it was doing.
+/// In a library, this also takes the decision of how to handle the error away
+/// from the caller. Instead, pass `stat=` and, optionally, `errmsg=` to get a
+/// description of the error, and handle it:
+///
+/// ```f90
+/// allocate(x(n), stat=stat, errmsg=message)
+/// if (stat /= 0) then
+///   print *, "Failed to allocate x: ", trim(message)
+///   return
+/// end if
+/// ```
+///
+/// As stopping may be the right thing to do in a main program, these can be
+/// allowed with the `check.allocate-stat.exempt-programs` setting.
+///
+/// ## Options
+/// - `check.allocate-stat.exempt-programs`
+#[derive(ViolationMetadata)]
+pub(crate) struct MissingAllocateStat {
+    statement: &'static str,
+}
+
+impl Violation for MissingAllocateStat {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { statement } = self;
+        format!("'{statement}' without 'stat='")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Add 'stat=' and 'errmsg=', and handle any errors".to_string())
+    }
+}
+
+impl AstRule for MissingAllocateStat {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if settings.check.allocate_stat.exempt_programs && in_program(node) {
+            return None;
+        }
+        if keyword_value(node, "stat", src.source_text()).is_some() {
+            return None;
+        }
+        let statement = statement_name(node);
+        some_vec![Diagnostic::from_node(Self { statement }, node)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["allocate_statement", "deallocate_statement"]
+    }
+}
+
+/// ## What it does
+/// Checks for `stat=` variables of `allocate` and `deallocate` statements that
+/// are never read afterwards in the same block.
+///
+/// ## Why is this bad?
+/// Passing `stat=` stops the program from halting when the statement fails,
+/// so if the status isn't checked, the failure is silently ignored and the
+/// program carries on with unallocated or partially deallocated arrays.
+///
+/// The status counts as unchecked if the variable isn't used again before the
+/// end of the block containing the statement, or before it is overwritten. A
+/// status read by the condition of an enclosing `do while` loop counts as
+/// checked, as do status variables that are dummy arguments, which are
+/// assumed to be checked by the caller.
+///
+/// ## Options
+/// - `check.allocate-stat.exempt-programs`
+#[derive(ViolationMetadata)]
+pub(crate) struct UncheckedAllocateStat {
+    name: String,
+    statement: &'static str,
+}
+
+impl Violation for UncheckedAllocateStat {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name, statement } = self;
+        format!("'stat={name}' of '{statement}' is never checked")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name, .. } = self;
+        Some(format!("Check that '{name}' is zero"))
+    }
+}
+
+impl AstRule for UncheckedAllocateStat {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if settings.check.allocate_stat.exempt_programs && in_program(node) {
+            return None;
+        }
+        let text = src.source_text();
+        let stat = keyword_value(node, "stat", text)?;
+        if stat.kind() != "identifier" {
+            return None;
+        }
+        let name = stat.to_text(text)?;
+        if is_status_checked(node, name, text) {
+            return None;
+        }
+
+        let violation = Self {
+            name: name.to_string(),
+            statement: statement_name(node),
+        };
+        some_vec![Diagnostic::from_node(violation, &stat)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["allocate_statement", "deallocate_statement"]
+    }
+}
@@ -0,0 +1,111 @@
+use crate::ast::FortitudeNode;
+use crate::rules::utilities::{numeric_type, strip_unary};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for floating point numbers compared with `==`, `/=`, `.eq.` or
+/// `.ne.`, where one side is a `real` or `complex` literal, a variable
+/// declared as `real` or `complex` in an enclosing scope, or an expression
+/// involving them.
+///
+/// ## Why is this bad?
+/// Floating point arithmetic is inexact, so two calculations that should give
+/// the same answer mathematically will often differ in the last few bits. An
+/// exact comparison may then be unexpectedly false, or depend on the compiler,
+/// optimisation level or platform. Instead, compare against a tolerance:
+///
+/// ```f90
+/// if (abs(x - y) < tolerance) then
+/// ```
+///
+/// where the tolerance is chosen to suit the calculation, for example using
+/// `epsilon(x)` relative to the size of the values.
+///
+/// Comparing a variable against itself, such as `x /= x`, is sometimes used to
+/// check for NaN. This isn't guaranteed to work, particularly with aggressive
+/// optimisation, and `ieee_is_nan` from the intrinsic module `ieee_arithmetic`
+/// should be used instead.
+///
+/// Comparisons against an exact zero are often deliberate, for example to
+/// avoid dividing by zero, and can be allowed with the
+/// `check.floating-point-equality.allow-zero` setting.
+///
+/// ## Options
+/// - `check.floating-point-equality.allow-zero`
+#[derive(ViolationMetadata)]
+pub(crate) struct FloatingPointEquality {
+    operator: String,
+    nan_check: bool,
+}
+
+impl Violation for FloatingPointEquality {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { operator, .. } = self;
+        format!("floating point values compared with '{operator}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        if self.nan_check {
+            Some("Use 'ieee_is_nan' from 'ieee_arithmetic' to check for NaN".to_string())
+        } else {
+            Some("Compare the difference against a tolerance instead".to_string())
+        }
+    }
+}
+
+impl AstRule for FloatingPointEquality {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let operator = node.child(1)?.to_text(text)?.to_lowercase();
+        if !matches!(operator.as_str(), "==" | "/=" | ".eq." | ".ne.") {
+            return None;
+        }
+
+        let left = node.child_by_field_name("left")?;
+        let right = node.child_by_field_name("right")?;
+        if !is_floating_point(&left, text) && !is_floating_point(&right, text) {
+            return None;
+        }
+
+        let allow_zero = settings.check.floating_point_equality.allow_zero;
+        if allow_zero && (is_zero(&left, text) || is_zero(&right, text)) {
+            return None;
+        }
+
+        // Comparing a value with itself is an attempt to check for NaN
+        let nan_check = left
+            .to_text(text)?
+            .eq_ignore_ascii_case(right.to_text(text)?);
+        let violation = Self {
+            operator,
+            nan_check,
+        };
+        some_vec![Diagnostic::from_node(violation, node)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["relational_expression"]
+    }
+}
+
+/// Is `node` a `real` or `complex` value
+fn is_floating_point(node: &Node, src: &str) -> bool {
+    numeric_type(node, src).is_some_and(|dtype| dtype.is_floating_point())
+}
+
+/// Is `node` a literal with the value zero, such as `0.0`, `0` or `0.0d0`
+fn is_zero(node: &Node, src: &str) -> bool {
+    let node = strip_unary(node);
+    node.kind() == "number_literal"
+        && node
+            .to_text(src)
+            .and_then(|literal| literal.split('_').next())
+            .and_then(|number| number.replace(['d', 'D'], "e").parse::<f64>().ok())
+            .is_some_and(|value| value == 0.0)
+}
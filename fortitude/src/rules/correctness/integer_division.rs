@@ -0,0 +1,140 @@
+use crate::ast::FortitudeNode;
+use crate::rules::utilities::{literal_type, numeric_type, NumericType, RealKind};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for division of one integer by another, where the result is then
+/// used in a `real` or `complex` expression or assigned to a `real` or
+/// `complex` variable.
+///
+/// ## Why is this bad?
+/// Dividing two integers gives an integer, with any remainder thrown away, so
+/// `1/3` is `0`. This happens before the result is converted to `real`, so in:
+///
+/// ```f90
+/// real(dp) :: x, y
+/// x = 1/3 * y
+/// ```
+///
+/// `x` is always zero. Make at least one side of the division `real`:
+///
+/// ```f90
+/// x = 1.0_dp/3 * y
+/// ```
+///
+/// or, if the truncation is intended, make it explicit with an intrinsic such
+/// as `floor` or `int`.
+///
+/// Only integer literals, and variables declared as `integer` in an enclosing
+/// scope, are recognised as integers.
+///
+/// ## Fix
+/// Where one side of the division is a literal, the fix writes it as a `real`
+/// literal with the same kind as the rest of the expression. This is unsafe,
+/// as the truncation may have been intended.
+#[derive(ViolationMetadata)]
+pub(crate) struct TruncatedIntegerDivision {
+    division: String,
+    promoted: Option<String>,
+}
+
+impl Violation for TruncatedIntegerDivision {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { division, .. } = self;
+        format!("integer division '{division}' is truncated before it is used as a real")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { promoted, .. } = self;
+        promoted
+            .as_ref()
+            .map(|promoted| format!("Divide as real using '{promoted}'"))
+    }
+}
+
+impl AstRule for TruncatedIntegerDivision {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        if node.child(1)?.kind() != "/" {
+            return None;
+        }
+
+        let left = node.child_by_field_name("left")?;
+        let right = node.child_by_field_name("right")?;
+        if numeric_type(&left, text)? != NumericType::Integer
+            || numeric_type(&right, text)? != NumericType::Integer
+        {
+            return None;
+        }
+
+        let kind = real_context(node, text)?;
+
+        // Promote the numerator if we can, as that reads most naturally
+        let literal = [left, right].into_iter().find(|operand| {
+            operand.kind() == "number_literal"
+                && operand
+                    .to_text(text)
+                    .is_some_and(|literal| literal_type(literal) == NumericType::Integer)
+        });
+        let promoted = literal.and_then(|literal| {
+            let digits = literal.to_text(text)?.split('_').next()?;
+            Some(format!("{digits}.0{}", kind.literal_suffix()))
+        });
+
+        let division = node.to_text(text)?.to_string();
+        let violation = Self {
+            division,
+            promoted: promoted.clone(),
+        };
+        let diagnostic = Diagnostic::from_node(violation, node);
+
+        match (literal, promoted) {
+            (Some(literal), Some(promoted)) => {
+                let fix = Fix::unsafe_edit(literal.edit_replacement(src, promoted));
+                some_vec![diagnostic.with_fix(fix)]
+            }
+            _ => some_vec![diagnostic],
+        }
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["math_expression"]
+    }
+}
+
+/// If the result of `node` is used in a `real` or `complex` expression or
+/// assignment, get the kind of that expression
+fn real_context(node: &Node, src: &str) -> Option<RealKind> {
+    let mut child = *node;
+    while let Some(parent) = child.parent() {
+        match parent.kind() {
+            "parenthesized_expression" | "unary_expression" => {}
+            "math_expression" => {
+                let left = parent.child_by_field_name("left")?;
+                let right = parent.child_by_field_name("right")?;
+                let other = if left == child { right } else { left };
+                if let Some(kind) = numeric_type(&other, src)?.real_kind() {
+                    return Some(kind.clone());
+                }
+            }
+            "assignment_statement" => {
+                let target = parent.child_by_field_name("left")?;
+                if target == child {
+                    return None;
+                }
+                return numeric_type(&target, src)?.real_kind().cloned();
+            }
+            _ => return None,
+        }
+        child = parent;
+    }
+    None
+}
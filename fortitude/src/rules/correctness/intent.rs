@@ -1,11 +1,134 @@
 use crate::ast::FortitudeNode;
 use crate::settings::Settings;
 use crate::{AstRule, FromAstNode};
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, ViolationMetadata};
 use ruff_source_file::SourceFile;
+use ruff_text_size::TextSize;
+use rustc_hash::FxHashMap;
 use tree_sitter::Node;
 
+/// The `intent` of a dummy argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Intent {
+    In,
+    Out,
+    InOut,
+}
+
+impl std::fmt::Display for Intent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::In => write!(f, "in"),
+            Self::Out => write!(f, "out"),
+            Self::InOut => write!(f, "inout"),
+        }
+    }
+}
+
+impl Intent {
+    /// Parse an `intent(...)` attribute, returning `None` for any other attribute
+    fn from_attribute(attribute: &Node, src: &str) -> Option<Self> {
+        let text: String = attribute
+            .to_text(src)?
+            .to_lowercase()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        match text.strip_prefix("intent(")?.strip_suffix(')')? {
+            "in" => Some(Self::In),
+            "out" => Some(Self::Out),
+            "inout" => Some(Self::InOut),
+            _ => None,
+        }
+    }
+}
+
+/// The declaration of a dummy argument
+pub(crate) struct DummyArgument<'a> {
+    /// The name, as written in the declaration
+    pub name: &'a str,
+    /// The declarator, such as `x` or `x(:)`
+    pub declarator: Node<'a>,
+    /// The `variable_declaration` containing the declarator
+    pub declaration: Node<'a>,
+    pub intent: Option<Intent>,
+}
+
+impl DummyArgument<'_> {
+    /// Whether the argument is declared with the `pointer` attribute
+    fn is_pointer(&self, src: &str) -> bool {
+        self.declaration
+            .children_by_field_name("attribute", &mut self.declaration.walk())
+            .any(|attr| {
+                attr.to_text(src)
+                    .is_some_and(|text| text.eq_ignore_ascii_case("pointer"))
+            })
+    }
+}
+
+/// Get the names of the dummy arguments from a `function_statement` or
+/// `subroutine_statement`
+fn parameter_names<'a>(statement: &Node, src: &'a str) -> Vec<&'a str> {
+    statement
+        .child_by_field_name("parameters")
+        .map(|parameters| {
+            parameters
+                .named_children(&mut parameters.walk())
+                .filter_map(|param| param.to_text(src))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Get the declarations of the dummy arguments of a `function` or
+/// `subroutine`. Dummy procedures are skipped, as they can't have an `intent`.
+pub(crate) fn dummy_arguments<'a>(procedure: &Node<'a>, src: &'a str) -> Vec<DummyArgument<'a>> {
+    let Some(statement) = procedure.named_child(0) else {
+        return Vec::new();
+    };
+    let parameters = parameter_names(&statement, src);
+
+    procedure
+        .named_children(&mut procedure.walk())
+        .filter(|child| child.kind() == "variable_declaration")
+        .filter(|decl| {
+            decl.child_by_field_name("type")
+                .is_some_and(|type_| type_.kind() != "procedure")
+        })
+        .flat_map(|decl| {
+            let intent = decl
+                .children_by_field_name("attribute", &mut decl.walk())
+                .find_map(|attr| Intent::from_attribute(&attr, src));
+
+            decl.children_by_field_name("declarator", &mut decl.walk())
+                .filter_map(|declarator| {
+                    let identifier = match declarator.kind() {
+                        "identifier" => Some(declarator),
+                        "sized_declarator" => declarator.child_with_name("identifier"),
+                        // Although tree-sitter-fortran grammar allows
+                        // `init_declarator` and `pointer_init_declarator`
+                        // here, dummy arguments aren't actually allow
+                        // initialisers. _Could_ still catch them here, and
+                        // flag as syntax error elsewhere?
+                        _ => None,
+                    }?;
+                    let name = identifier.to_text(src)?;
+                    parameters
+                        .iter()
+                        .any(|param| param.eq_ignore_ascii_case(name))
+                        .then_some(DummyArgument {
+                            name,
+                            declarator,
+                            declaration: decl,
+                            intent,
+                        })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 /// ## What it does
 /// Checks for missing `intent` on dummy arguments
 ///
@@ -24,93 +147,768 @@ use tree_sitter::Node;
 /// Finally, `intent(inout)` arguments can be both read and modified by the
 /// routine. If an `intent` is not specified, it will default to
 /// `intent(inout)`.
+///
+/// ## Fix
+/// The fix infers the most restrictive `intent` from how the argument is used
+/// in the body of the procedure: arguments that are only read are given
+/// `intent(in)`, those that are unconditionally assigned before being read
+/// are given `intent(out)`, and everything else is given `intent(inout)`.
+/// Declarations of several entities are split if they need different intents.
+///
+/// This fix is unsafe, as it can't account for every way an argument might
+/// be used, such as through procedures defined in other files, and may change
+/// the behaviour of the program.
 #[derive(ViolationMetadata)]
 pub(crate) struct MissingIntent {
     entity: String,
     name: String,
+    intent: Option<Intent>,
 }
 
 impl Violation for MissingIntent {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
-        let Self { entity, name } = self;
+        let Self { entity, name, .. } = self;
         format!("{entity} argument '{name}' missing 'intent' attribute")
     }
+
+    fn fix_title(&self) -> Option<String> {
+        let intent = self.intent?;
+        Some(format!("Add 'intent({intent})'"))
+    }
 }
 
 impl AstRule for MissingIntent {
     fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
-        let src = src.source_text();
-        // Names of all the dummy arguments
-        let parameters: Vec<&str> = node
-            .child_by_field_name("parameters")?
-            .named_children(&mut node.walk())
-            .filter_map(|param| param.to_text(src))
-            .collect();
+        let text = src.source_text();
+        let procedures = Procedures::new(node, text);
 
-        let parent = node.parent()?;
-        let entity = parent.kind().to_string();
-
-        // Logic here is:
-        // 1. find variable declarations
-        // 2. ignore `procedure` arguments
-        // 3. filter to the declarations that don't have an `intent`
-        // 4. filter to the ones that contain any of the dummy arguments
-        // 5. collect into a vec of violations
-        //
-        // We filter by missing intent first, so we only have to
-        // filter by the dummy args once -- otherwise we either catch
-        // local var decls on the same line, or need to iterate over
-        // the decl names twice
-        let violations = parent
-            .named_children(&mut parent.walk())
-            .filter(|child| child.kind() == "variable_declaration")
-            .filter(|decl| {
-                if let Some(type_) = decl.child_by_field_name("type") {
-                    type_.kind() != "procedure"
+        let violations = node
+            .named_descendants()
+            .filter(|node| matches!(node.kind(), "function" | "subroutine"))
+            .collect::<Vec<_>>()
+            .iter()
+            .flat_map(|procedure| {
+                let dummies: Vec<_> = dummy_arguments(procedure, text)
+                    .into_iter()
+                    .filter(|dummy| dummy.intent.is_none())
+                    .collect();
+
+                // Interface bodies don't tell us anything about how the
+                // arguments are used
+                let in_interface = procedure
+                    .ancestors()
+                    .any(|parent| parent.kind() == "interface");
+                let intents: Vec<Option<Intent>> = if in_interface {
+                    vec![None; dummies.len()]
                 } else {
-                    false
-                }
-            })
-            .filter(|decl| {
-                !decl
-                    .children_by_field_name("attribute", &mut decl.walk())
-                    .any(|attr| {
-                        attr.to_text(src)
-                            .unwrap_or("")
-                            .to_lowercase()
-                            .starts_with("intent")
+                    let (body, internal) = find_modifications(procedure, text, &procedures);
+                    dummies
+                        .iter()
+                        .map(|dummy| {
+                            Some(infer_intent(dummy.name, procedure, text, &body, &internal))
+                        })
+                        .collect()
+                };
+
+                dummies
+                    .iter()
+                    .zip(intents.iter())
+                    .map(|(dummy, intent)| {
+                        let diagnostic = Diagnostic::from_node(
+                            Self {
+                                entity: procedure.kind().to_string(),
+                                name: dummy.name.to_string(),
+                                intent: *intent,
+                            },
+                            &dummy.declarator,
+                        );
+                        if intent.is_none() {
+                            return diagnostic;
+                        }
+                        // Every diagnostic in a declaration gets the same
+                        // fix, which adds the intents for all of them
+                        let declaration_intents: Vec<_> = dummies
+                            .iter()
+                            .zip(intents.iter())
+                            .filter(|(other, _)| other.declaration == dummy.declaration)
+                            .map(|(other, intent)| (other.declarator, *intent))
+                            .collect();
+                        match add_intents(&dummy.declaration, &declaration_intents, src) {
+                            Some(edit) => diagnostic.with_fix(Fix::unsafe_edit(edit)),
+                            None => diagnostic,
+                        }
                     })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["translation_unit"]
+    }
+}
+
+/// Get the identifiers in a procedure that refer to the variable `name`, in
+/// the order they appear. Declarations and keyword argument names are skipped.
+fn references<'a>(name: &str, procedure: &'a Node<'a>, src: &str) -> Vec<Node<'a>> {
+    procedure
+        .named_descendants()
+        .filter(|node| node.kind() == "identifier")
+        .filter(|node| {
+            node.to_text(src)
+                .is_some_and(|text| text.eq_ignore_ascii_case(name))
+        })
+        .filter(|node| {
+            let Some(parent) = node.parent() else {
+                return false;
+            };
+            match parent.kind() {
+                "keyword_argument" => parent.child_by_field_name("name") != Some(*node),
+                "derived_type_member_expression" => parent.named_child(0) == Some(*node),
+                _ => true,
+            }
+        })
+        .filter(|node| {
+            !node.ancestors().any(|parent| {
+                matches!(
+                    parent.kind(),
+                    "variable_declaration"
+                        | "subroutine_statement"
+                        | "function_statement"
+                        | "end_subroutine_statement"
+                        | "end_function_statement"
+                )
             })
-            .flat_map(|decl| {
-                decl.children_by_field_name("declarator", &mut decl.walk())
-                    .filter_map(|declarator| {
-                        let identifier = match declarator.kind() {
-                            "identifier" => Some(declarator),
-                            "sized_declarator" => declarator.child_with_name("identifier"),
-                            // Although tree-sitter-fortran grammar allows
-                            // `init_declarator` and `pointer_init_declarator`
-                            // here, dummy arguments aren't actually allow
-                            // initialisers. _Could_ still catch them here, and
-                            // flag as syntax error elsewhere?
-                            _ => None,
-                        }?;
-                        let name = identifier.to_text(src)?;
-                        if parameters.contains(&name) {
-                            return Some((declarator, name));
+        })
+        .collect()
+}
+
+/// Infer the most restrictive `intent` that a dummy argument could have from
+/// how it is used in the procedure
+fn infer_intent(
+    name: &str,
+    procedure: &Node,
+    src: &str,
+    body: &[Modification],
+    internal: &[Modification],
+) -> Intent {
+    let modifications: Vec<_> = body
+        .iter()
+        .chain(internal.iter())
+        .filter(|modification| modification.name.eq_ignore_ascii_case(name))
+        .collect();
+
+    if modifications.is_empty() {
+        return Intent::In;
+    }
+    if modifications
+        .iter()
+        .any(|modification| modification.certainty != Certainty::Certain)
+    {
+        return Intent::InOut;
+    }
+
+    let references = references(name, procedure, src);
+    let Some(first) = references.first() else {
+        return Intent::InOut;
+    };
+
+    // The first use must set the whole variable, and not also read it in the
+    // same statement, as in `x = x + 1`
+    let Some(write) = body.iter().find(|modification| {
+        modification.node.start_byte() == first.start_byte()
+            && matches!(
+                modification.node.kind(),
+                "identifier" | "sized_allocate_object"
+            )
+    }) else {
+        return Intent::InOut;
+    };
+    let statement = write
+        .node
+        .ancestors()
+        .find(|parent| parent.kind().ends_with("_statement"));
+
+    // The write must always happen, so it can't be inside an `if` or a loop
+    let is_unconditional = statement.and_then(|statement| statement.parent()) == Some(*procedure);
+    if !is_unconditional {
+        return Intent::InOut;
+    }
+
+    let read_in_same_statement = references.iter().skip(1).any(|reference| {
+        reference
+            .ancestors()
+            .any(|parent| Some(parent) == statement)
+    });
+
+    if read_in_same_statement {
+        Intent::InOut
+    } else {
+        Intent::Out
+    }
+}
+
+/// Create an edit adding `intent` attributes to the dummy arguments in a
+/// `variable_declaration`, splitting the declaration if there are local
+/// variables or different intents in it
+fn add_intents(
+    declaration: &Node,
+    intents: &[(Node, Option<Intent>)],
+    src: &SourceFile,
+) -> Option<Edit> {
+    let text = src.source_text();
+    let declarators: Vec<_> = declaration
+        .children_by_field_name("declarator", &mut declaration.walk())
+        .collect();
+    let first_declarator = declarators.first()?;
+
+    // Everything before the entities, such as `integer, dimension(:)`
+    let prefix = text[declaration.start_byte()..first_declarator.start_byte()]
+        .trim_end()
+        .trim_end_matches("::")
+        .trim_end()
+        .trim_end_matches(',')
+        .trim_end();
+    let prefix_end = TextSize::try_from(declaration.start_byte() + prefix.len()).unwrap();
+
+    // Group the entities by intent, in the order they first appear
+    let mut groups: Vec<(Option<Intent>, Vec<&str>)> = Vec::new();
+    for declarator in &declarators {
+        let intent = intents
+            .iter()
+            .find(|(dummy, _)| dummy == declarator)
+            .and_then(|(_, intent)| *intent);
+        let entity = declarator.to_text(text)?;
+        match groups.iter_mut().find(|(group, _)| *group == intent) {
+            Some((_, entities)) => entities.push(entity),
+            None => groups.push((intent, vec![entity])),
+        }
+    }
+
+    if let [(Some(intent), _)] = groups.as_slice() {
+        let has_colons = declaration
+            .children(&mut declaration.walk())
+            .any(|child| child.kind() == "::");
+        let colons = if has_colons { "" } else { " ::" };
+        return Some(Edit::insertion(
+            format!(", intent({intent}){colons}"),
+            prefix_end,
+        ));
+    }
+
+    let indentation = declaration.indentation(src);
+    let content = groups
+        .iter()
+        .map(|(intent, entities)| {
+            let entities = entities.join(", ");
+            match intent {
+                Some(intent) => format!("{prefix}, intent({intent}) :: {entities}"),
+                None => format!("{prefix} :: {entities}"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(&format!("\n{indentation}"));
+    Some(declaration.edit_replacement(src, content))
+}
+
+/// How sure we are that a statement modifies a variable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Certainty {
+    /// The variable is definitely modified, for example by assignment
+    Certain,
+    /// The variable might be modified, for example by passing it to a
+    /// procedure in this file that doesn't give the argument an `intent`
+    Possible,
+    /// We can't tell if the variable is modified, for example when passing
+    /// it to a procedure from another file
+    Unknown,
+}
+
+impl Certainty {
+    /// How passing a variable to a dummy argument with this `intent` modifies it
+    fn from_intent(intent: Option<Intent>) -> Option<Self> {
+        match intent {
+            Some(Intent::In) => None,
+            Some(Intent::Out | Intent::InOut) => Some(Self::Certain),
+            None => Some(Self::Possible),
+        }
+    }
+}
+
+/// A variable modified by a statement
+struct Modification<'a> {
+    /// Lowercase name of the variable
+    name: String,
+    /// The expression that is modified, such as `x(i)` in `x(i) = 1`
+    node: Node<'a>,
+    certainty: Certainty,
+}
+
+/// I/O and allocation specifiers that set the value of their argument
+const OUTPUT_SPECIFIERS: &[&str] = &["iostat", "iomsg", "stat", "errmsg", "newunit", "size"];
+
+/// Specifiers of an `inquire` statement that are inputs. All others are set by
+/// the statement.
+const INQUIRE_INPUTS: &[&str] = &["unit", "file", "id"];
+
+/// The dummy arguments of the procedures defined in a file, including those
+/// in interface blocks, by lowercase procedure name
+struct Procedures(FxHashMap<String, Vec<(String, Option<Intent>)>>);
+
+impl Procedures {
+    fn new(root: &Node, src: &str) -> Self {
+        let mut procedures = FxHashMap::default();
+        for procedure in root
+            .named_descendants()
+            .filter(|node| matches!(node.kind(), "function" | "subroutine"))
+        {
+            let Some(statement) = procedure.named_child(0) else {
+                continue;
+            };
+            let Some(name) = statement
+                .child_by_field_name("name")
+                .and_then(|name| name.to_text(src))
+            else {
+                continue;
+            };
+            let dummies = dummy_arguments(&procedure, src);
+            let signature = parameter_names(&statement, src)
+                .into_iter()
+                .map(|param| {
+                    let intent = dummies
+                        .iter()
+                        .find(|dummy| dummy.name.eq_ignore_ascii_case(param))
+                        .and_then(|dummy| dummy.intent);
+                    (param.to_lowercase(), intent)
+                })
+                .collect();
+            procedures.entry(name.to_lowercase()).or_insert(signature);
+        }
+        Self(procedures)
+    }
+
+    /// Get the `intent` of the dummy argument that matches the actual
+    /// argument at `position`, or named `keyword`. Returns `None` if the
+    /// procedure isn't defined in this file.
+    fn intent(
+        &self,
+        procedure: &str,
+        position: Option<usize>,
+        keyword: Option<&str>,
+    ) -> Option<Option<Intent>> {
+        let signature = self.0.get(&procedure.to_lowercase())?;
+        let dummy = match keyword {
+            Some(keyword) => signature
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(keyword)),
+            None => position.and_then(|position| signature.get(position)),
+        };
+        // If we can't match up the arguments, assume the worst
+        Some(dummy.and_then(|(_, intent)| *intent))
+    }
+}
+
+/// Get the name of the variable referred to by an expression such as `x`,
+/// `x(i)`, or `x%y`
+fn variable_name<'a>(node: &Node, src: &'a str) -> Option<&'a str> {
+    match node.kind() {
+        "identifier" => node.to_text(src),
+        "call_expression" | "derived_type_member_expression" | "sized_allocate_object" => {
+            variable_name(&node.named_child(0)?, src)
+        }
+        _ => None,
+    }
+}
+
+/// Collects the variables modified by statements
+struct ModificationFinder<'a, 'b> {
+    src: &'a str,
+    procedures: &'b Procedures,
+    modifications: Vec<Modification<'a>>,
+}
+
+impl<'a> ModificationFinder<'a, '_> {
+    fn push(&mut self, node: Node<'a>, certainty: Certainty) {
+        if let Some(name) = variable_name(&node, self.src) {
+            self.modifications.push(Modification {
+                name: name.to_lowercase(),
+                node,
+                certainty,
+            });
+        }
+    }
+
+    /// Record the values of keyword arguments of `node` that are set by it
+    fn push_specifiers(&mut self, node: &Node<'a>, is_output: impl Fn(&str) -> bool) {
+        for arg in node.named_children(&mut node.walk()) {
+            if arg.kind() != "keyword_argument" {
+                continue;
+            }
+            let Some(keyword) = arg
+                .child_by_field_name("name")
+                .and_then(|name| name.to_text(self.src))
+            else {
+                continue;
+            };
+            if is_output(&keyword.to_lowercase()) {
+                if let Some(value) = arg.child_by_field_name("value") {
+                    self.push(value, Certainty::Certain);
+                }
+            }
+        }
+    }
+
+    /// Record the actual arguments of a procedure call that may be modified
+    fn push_arguments(&mut self, callee: &Node, arguments: &Node<'a>) {
+        let callee = callee
+            .to_text(self.src)
+            .filter(|_| callee.kind() == "identifier");
+
+        let mut positional = 0;
+        for arg in arguments.named_children(&mut arguments.walk()) {
+            let (position, keyword, value) = if arg.kind() == "keyword_argument" {
+                let keyword = arg
+                    .child_by_field_name("name")
+                    .and_then(|name| name.to_text(self.src));
+                (None, keyword, arg.child_by_field_name("value"))
+            } else {
+                positional += 1;
+                (Some(positional - 1), None, Some(arg))
+            };
+            let Some(value) = value else {
+                continue;
+            };
+
+            let intent =
+                callee.and_then(|callee| self.procedures.intent(callee, position, keyword));
+            let certainty = match intent {
+                Some(intent) => Certainty::from_intent(intent),
+                // Nothing is known about procedures from other files
+                None => Some(Certainty::Unknown),
+            };
+            if let Some(certainty) = certainty {
+                self.push(value, certainty);
+            }
+        }
+    }
+
+    /// Record the variables modified by a single node
+    fn visit(&mut self, node: Node<'a>) {
+        match node.kind() {
+            "assignment_statement" | "pointer_association_statement" => {
+                if let Some(target) = node
+                    .child_by_field_name("left")
+                    .or_else(|| node.named_child(0))
+                {
+                    self.push(target, Certainty::Certain);
+                }
+            }
+            "loop_control_expression" => {
+                if let Some(variable) = node.named_child(0) {
+                    self.push(variable, Certainty::Certain);
+                }
+            }
+            "allocate_statement" | "deallocate_statement" | "nullify_statement" => {
+                for object in node.named_children(&mut node.walk()) {
+                    if matches!(
+                        object.kind(),
+                        "keyword_argument" | "intrinsic_type" | "derived_type"
+                    ) {
+                        continue;
+                    }
+                    self.push(object, Certainty::Certain);
+                }
+                self.push_specifiers(&node, |keyword| OUTPUT_SPECIFIERS.contains(&keyword));
+            }
+            "read_statement" => {
+                for child in node.named_children(&mut node.walk()) {
+                    if child.kind() == "input_item_list" {
+                        for item in child.named_children(&mut child.walk()) {
+                            self.push_input_item(item);
                         }
-                        None
+                    } else {
+                        self.push_input_item(child);
+                    }
+                }
+                self.push_specifiers(&node, |keyword| OUTPUT_SPECIFIERS.contains(&keyword));
+            }
+            "write_statement" => {
+                // Internal writes modify a character variable, but we can't
+                // tell those apart from integer unit numbers
+                if let Some(unit) = node
+                    .child_with_name("unit_identifier")
+                    .and_then(|unit| unit.named_child(0))
+                {
+                    self.push(unit, Certainty::Possible);
+                }
+                self.push_specifiers(&node, |keyword| OUTPUT_SPECIFIERS.contains(&keyword));
+            }
+            "open_statement"
+            | "close_statement"
+            | "wait_statement"
+            | "flush_statement"
+            | "file_position_statement" => {
+                self.push_specifiers(&node, |keyword| OUTPUT_SPECIFIERS.contains(&keyword));
+            }
+            "inquire_statement" => {
+                self.push_specifiers(&node, |keyword| !INQUIRE_INPUTS.contains(&keyword));
+            }
+            "subroutine_call" => {
+                let Some(callee) = node
+                    .child_by_field_name("subroutine")
+                    .or_else(|| node.named_child(0))
+                else {
+                    return;
+                };
+                // Type-bound procedures may modify the object they're bound to
+                if callee.kind() == "derived_type_member_expression" {
+                    self.push(callee, Certainty::Unknown);
+                }
+                if let Some(arguments) = node.child_with_name("argument_list") {
+                    self.push_arguments(&callee, &arguments);
+                }
+            }
+            "call_expression" => {
+                // This could also be an array element, so only consider
+                // functions defined in this file
+                let Some(callee) = node.named_child(0) else {
+                    return;
+                };
+                let is_known_function = callee.kind() == "identifier"
+                    && callee
+                        .to_text(self.src)
+                        .is_some_and(|name| self.procedures.0.contains_key(&name.to_lowercase()));
+                if is_known_function {
+                    if let Some(arguments) = node.child_with_name("argument_list") {
+                        self.push_arguments(&callee, &arguments);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_input_item(&mut self, item: Node<'a>) {
+        match item.kind() {
+            "implied_do_loop_expression" => {
+                for child in item.named_children(&mut item.walk()) {
+                    self.push_input_item(child);
+                }
+            }
+            "unit_identifier" | "format_identifier" | "keyword_argument" => {}
+            _ => self.push(item, Certainty::Certain),
+        }
+    }
+}
+
+/// Find the variables modified in the body of a procedure, ignoring any
+/// internal procedures, and separately, those modified in its internal
+/// procedures
+fn find_modifications<'a>(
+    procedure: &'a Node<'a>,
+    src: &'a str,
+    procedures: &Procedures,
+) -> (Vec<Modification<'a>>, Vec<Modification<'a>>) {
+    let mut body = ModificationFinder {
+        src,
+        procedures,
+        modifications: Vec::new(),
+    };
+    for node in procedure.named_descendants_except(["internal_procedures"]) {
+        body.visit(node);
+    }
+
+    let mut internal = ModificationFinder {
+        src,
+        procedures,
+        modifications: Vec::new(),
+    };
+    for node in procedure.named_descendants().filter(|node| {
+        node.ancestors()
+            .take_while(|parent| *parent != *procedure)
+            .any(|parent| parent.kind() == "internal_procedures")
+    }) {
+        internal.visit(node);
+    }
+
+    (body.modifications, internal.modifications)
+}
+
+/// Whether a modification of a `pointer` changes its association, rather than
+/// the value of its target, as in `p => x`, `nullify(p)` or `allocate(p)`
+fn is_reassociation(modification: &Modification) -> bool {
+    modification.node.parent().is_some_and(|statement| {
+        matches!(
+            statement.kind(),
+            "pointer_association_statement"
+                | "nullify_statement"
+                | "allocate_statement"
+                | "deallocate_statement"
+        )
+    }) && matches!(
+        modification.node.kind(),
+        "identifier" | "sized_allocate_object"
+    )
+}
+
+/// Get all the procedures in the file with a body, skipping interfaces
+fn procedures_with_bodies<'a>(root: &Node<'a>) -> Vec<Node<'a>> {
+    root.named_descendants()
+        .filter(|node| matches!(node.kind(), "function" | "subroutine"))
+        .filter(|node| !node.ancestors().any(|parent| parent.kind() == "interface"))
+        .collect()
+}
+
+/// ## What it does
+/// Checks for dummy arguments with `intent(in)` that are modified by the
+/// procedure.
+///
+/// ## Why is this bad?
+/// Arguments with `intent(in)` are read-only, and modifying them is an error.
+/// Compilers will catch direct assignments, but not always other ways of
+/// modifying a variable, such as passing it to a procedure where the
+/// corresponding argument is `intent(out)` or `intent(inout)`. Either the
+/// argument shouldn't be modified, or it should be `intent(inout)`.
+///
+/// This rule checks assignments, `allocate` and `deallocate`, `read`
+/// statements, I/O status specifiers like `iostat=`, and passing the argument
+/// to `intent(out)` or `intent(inout)` arguments of procedures defined in the
+/// same file.
+///
+/// For `pointer` arguments, `intent(in)` only prevents changing what the
+/// pointer is associated with, so only pointer assignment, `nullify`,
+/// `allocate` and `deallocate` are reported. Assigning a value to the target,
+/// as in `p = 1`, is allowed.
+#[derive(ViolationMetadata)]
+pub(crate) struct ModifiedIntentIn {
+    name: String,
+}
+
+impl Violation for ModifiedIntentIn {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'intent(in)' argument '{name}' is modified")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Change to 'intent(inout)', or don't modify the argument".to_string())
+    }
+}
+
+impl AstRule for ModifiedIntentIn {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let src = src.source_text();
+        let procedures = Procedures::new(node, src);
+
+        let violations = procedures_with_bodies(node)
+            .iter()
+            .flat_map(|procedure| {
+                let (modifications, _) = find_modifications(procedure, src, &procedures);
+                dummy_arguments(procedure, src)
+                    .into_iter()
+                    .filter(|dummy| dummy.intent == Some(Intent::In))
+                    .flat_map(|dummy| {
+                        let is_pointer = dummy.is_pointer(src);
+                        modifications
+                            .iter()
+                            .filter(|modification| {
+                                modification.certainty == Certainty::Certain
+                                    && dummy.name.eq_ignore_ascii_case(&modification.name)
+                                    && (!is_pointer || is_reassociation(modification))
+                            })
+                            .map(|modification| {
+                                Diagnostic::from_node(
+                                    Self {
+                                        name: dummy.name.to_string(),
+                                    },
+                                    &modification.node,
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["translation_unit"]
+    }
+}
+
+/// ## What it does
+/// Checks for dummy arguments with `intent(out)` that are never assigned a
+/// value.
+///
+/// ## Why is this bad?
+/// Arguments with `intent(out)` are undefined on entry to the procedure, and
+/// the caller will expect them to have been set on return. If the procedure
+/// never sets the argument, the caller will read an undefined value, which
+/// compilers don't warn about. Either the argument should be set, or it
+/// should be `intent(in)` or `intent(inout)`.
+///
+/// This rule looks for assignments, `allocate`, `read` statements, I/O status
+/// specifiers like `iostat=`, and passing the argument to procedures defined
+/// in the same file that could modify it, including in internal procedures.
+/// It doesn't check that the argument is set on every path through the
+/// procedure.
+///
+/// Passing the argument to a procedure from another file, or calling one of
+/// its type-bound procedures, isn't enough to count as setting it, as we
+/// can't tell what the procedure does with it. If the procedure does set the
+/// argument, this can be silenced with an allow comment.
+#[derive(ViolationMetadata)]
+pub(crate) struct UnassignedIntentOut {
+    name: String,
+}
+
+impl Violation for UnassignedIntentOut {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'intent(out)' argument '{name}' is never assigned")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Assign a value, or change the 'intent'".to_string())
+    }
+}
+
+impl AstRule for UnassignedIntentOut {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let src = src.source_text();
+        let procedures = Procedures::new(node, src);
+
+        let violations = procedures_with_bodies(node)
+            .iter()
+            .flat_map(|procedure| {
+                let (body, internal) = find_modifications(procedure, src, &procedures);
+                dummy_arguments(procedure, src)
+                    .into_iter()
+                    .filter(|dummy| dummy.intent == Some(Intent::Out))
+                    .filter(|dummy| {
+                        !body.iter().chain(internal.iter()).any(|modification| {
+                            modification.certainty != Certainty::Unknown
+                                && dummy.name.eq_ignore_ascii_case(&modification.name)
+                        })
                     })
-                    .map(|(dummy, name)| {
+                    .map(|dummy| {
                         Diagnostic::from_node(
                             Self {
-                                entity: entity.to_string(),
-                                name: name.to_string(),
+                                name: dummy.name.to_string(),
                             },
-                            &dummy,
+                            &dummy.declarator,
                         )
                     })
-                    .collect::<Vec<Diagnostic>>()
+                    .collect::<Vec<_>>()
             })
             .collect();
 
@@ -118,6 +916,6 @@ impl AstRule for MissingIntent {
     }
 
     fn entrypoints() -> Vec<&'static str> {
-        vec!["function_statement", "subroutine_statement"]
+        vec!["translation_unit"]
     }
 }
@@ -1,4 +1,5 @@
 use crate::ast::FortitudeNode;
+use crate::rules::utilities::{is_status_checked, keyword_value, literal_as_io_unit};
 use crate::settings::Settings;
 use crate::{AstRule, FromAstNode};
 use ruff_diagnostics::{Diagnostic, Violation};
@@ -47,3 +48,229 @@ impl AstRule for MissingActionSpecifier {
         vec!["open_statement"]
     }
 }
+
+/// Get the keyword of an I/O statement, such as `read` or `open`
+fn statement_name(node: &Node, src: &str) -> Option<String> {
+    Some(node.child(0)?.to_text(src)?.to_lowercase())
+}
+
+/// Statements that can set an `iostat=` specifier
+const IOSTAT_STATEMENTS: &[&str] = &[
+    "open_statement",
+    "close_statement",
+    "read_statement",
+    "write_statement",
+    "inquire_statement",
+];
+
+/// ## What it does
+/// Checks for `open`, `close`, `read`, `write` and `inquire` statements
+/// without an `iostat=` specifier.
+///
+/// ## Why is this bad?
+/// If an I/O statement fails without `iostat=`, such as when a file is missing
+/// or its contents can't be read, the program stops immediately, usually with
+/// little indication of what it was doing. Instead, pass `iostat=` and
+/// `iomsg=` to get a description of the error, and handle it:
+///
+/// ```f90
+/// open(newunit=unit, file=filename, action="read", iostat=stat, iomsg=message)
+/// if (stat /= 0) then
+///   print *, "Failed to open ", filename, ": ", trim(message)
+///   return
+/// end if
+/// ```
+///
+/// Statements with an `err=` label already handle errors, and are allowed, as
+/// are `write` statements to the default unit, `*`, and statements without a
+/// control list, such as `read *, x`, which can't take `iostat=`.
+#[derive(ViolationMetadata)]
+pub(crate) struct MissingIostat {
+    statement: String,
+}
+
+impl Violation for MissingIostat {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { statement } = self;
+        format!("'{statement}' without 'iostat='")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Add 'iostat=' and 'iomsg=', and handle any errors".to_string())
+    }
+}
+
+impl AstRule for MissingIostat {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let txt = src.source_text();
+        // `read *, x` and `read '(a)', x` can't take any specifiers
+        if !node
+            .children(&mut node.walk())
+            .any(|child| child.kind() == "(")
+        {
+            return None;
+        }
+        if keyword_value(node, "iostat", txt).is_some() || keyword_value(node, "err", txt).is_some()
+        {
+            return None;
+        }
+        let statement = statement_name(node, txt)?;
+        if statement == "write" {
+            let unit = node
+                .child_with_name("unit_identifier")
+                .or_else(|| keyword_value(node, "unit", txt));
+            if unit.and_then(|unit| unit.to_text(txt)) == Some("*") {
+                return None;
+            }
+        }
+        some_vec![Diagnostic::from_node(Self { statement }, node)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        IOSTAT_STATEMENTS.to_vec()
+    }
+}
+
+/// ## What it does
+/// Checks for `iostat=` variables of I/O statements that are never read
+/// afterwards in the same block.
+///
+/// ## Why is this bad?
+/// Passing `iostat=` stops the program from halting when the statement fails,
+/// so if the status isn't checked, the failure is silently ignored and the
+/// program carries on with missing or partially read data. Check that the
+/// status is zero, or use `is_iostat_end` and `is_iostat_eor` from
+/// `iso_fortran_env` to handle the end of a file or record.
+///
+/// The status counts as unchecked if the variable isn't used again before the
+/// end of the block containing the statement, or before it is overwritten. A
+/// status read by the condition of an enclosing `do while` loop counts as
+/// checked, as do status variables that are dummy arguments, which are
+/// assumed to be checked by the caller.
+#[derive(ViolationMetadata)]
+pub(crate) struct UncheckedIostat {
+    name: String,
+    statement: String,
+}
+
+impl Violation for UncheckedIostat {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name, statement } = self;
+        format!("'iostat={name}' of '{statement}' is never checked")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name, .. } = self;
+        Some(format!("Check the value of '{name}'"))
+    }
+}
+
+impl AstRule for UncheckedIostat {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let txt = src.source_text();
+        let iostat = keyword_value(node, "iostat", txt)?;
+        if iostat.kind() != "identifier" {
+            return None;
+        }
+        let name = iostat.to_text(txt)?;
+        if is_status_checked(node, name, txt) {
+            return None;
+        }
+
+        let violation = Self {
+            name: name.to_string(),
+            statement: statement_name(node, txt)?,
+        };
+        some_vec![Diagnostic::from_node(violation, &iostat)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        IOSTAT_STATEMENTS.to_vec()
+    }
+}
+
+/// ## What it does
+/// Checks for missing status specifier when opening files.
+///
+/// ## Why is this bad?
+/// By default, files are opened with `status="unknown"`, which opens the file
+/// if it exists and creates it otherwise. This may not be the programmer's
+/// intent: an input file that is missing, perhaps because of a typo in its
+/// name, is silently created empty, and an existing output file may be
+/// partially overwritten. Explicitly specifying `old`, `new`, `replace` or
+/// `scratch` makes it clear how the file is intended to be used, and turns
+/// these mistakes into errors.
+#[derive(ViolationMetadata)]
+pub(crate) struct MissingStatusSpecifier {}
+
+impl Violation for MissingStatusSpecifier {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "file opened without status specifier".to_string()
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Add 'status=old', 'status=new', 'status=replace', or 'status=scratch'".to_string())
+    }
+}
+
+impl AstRule for MissingStatusSpecifier {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if keyword_value(node, "status", src.source_text()).is_some() {
+            return None;
+        }
+        some_vec![Diagnostic::from_node(Self {}, node)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["open_statement"]
+    }
+}
+
+/// ## What it does
+/// Checks for files opened with a literal integer as the unit, rather than
+/// with `newunit=`.
+///
+/// ## Why is this bad?
+/// A hardcoded unit number may already be in use elsewhere in the program,
+/// including in a library, in which case opening the file either fails or
+/// closes the other file. The `newunit=` specifier, available since Fortran
+/// 2008, chooses a unit that isn't in use and stores it in a variable:
+///
+/// ```f90
+/// integer :: unit
+/// open(newunit=unit, file="example.txt", action="read", status="old")
+/// ```
+///
+/// This complements `magic-io-unit` (`C032`), which checks for literal units
+/// in all I/O statements.
+#[derive(ViolationMetadata)]
+pub(crate) struct LiteralUnitInOpen {
+    value: String,
+}
+
+impl Violation for LiteralUnitInOpen {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { value } = self;
+        format!("file opened with literal unit '{value}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use 'newunit=' to get a unit that isn't in use".to_string())
+    }
+}
+
+impl AstRule for LiteralUnitInOpen {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let unit = literal_as_io_unit(node, src)?;
+        let value = unit.to_text(src.source_text())?.to_string();
+        some_vec![Diagnostic::from_node(Self { value }, &unit)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["open_statement"]
+    }
+}
@@ -1,16 +1,21 @@
 pub mod accessibility_statements;
+pub mod allocate_stat;
 pub mod assumed_size;
 pub mod derived_default_init;
 pub mod exit_labels;
 pub mod external;
+pub mod float_comparison;
 pub mod implicit_kinds;
 pub mod implicit_typing;
 pub mod init_decls;
+pub mod integer_division;
 pub mod intent;
 pub mod kind_suffixes;
 pub mod magic_numbers;
 pub mod missing_io_specifier;
+pub mod precision;
 pub mod select_default;
+pub mod settings;
 pub mod trailing_backslash;
 pub mod use_statements;
 
@@ -25,7 +30,10 @@ mod tests {
 
     use crate::apply_common_filters;
     use crate::registry::Rule;
-    use crate::settings::Settings;
+    use crate::rules::correctness::settings::{
+        AllocateStatSettings, FloatingPointEqualitySettings,
+    };
+    use crate::settings::{CheckSettings, Settings};
     use crate::test::test_path;
 
     #[test_case(Rule::ImplicitTyping, Path::new("C001.f90"))]
@@ -37,8 +45,14 @@ mod tests {
     #[test_case(Rule::MagicNumberInArraySize, Path::new("C031.f90"))]
     #[test_case(Rule::MagicIoUnit, Path::new("C032.f90"))]
     #[test_case(Rule::MissingActionSpecifier, Path::new("C041.f90"))]
+    #[test_case(Rule::MissingIostat, Path::new("C044.f90"))]
+    #[test_case(Rule::UncheckedIostat, Path::new("C045.f90"))]
+    #[test_case(Rule::MissingStatusSpecifier, Path::new("C046.f90"))]
+    #[test_case(Rule::LiteralUnitInOpen, Path::new("C047.f90"))]
     #[test_case(Rule::TrailingBackslash, Path::new("C051.F90"))]
     #[test_case(Rule::MissingIntent, Path::new("C061.f90"))]
+    #[test_case(Rule::ModifiedIntentIn, Path::new("C062.f90"))]
+    #[test_case(Rule::UnassignedIntentOut, Path::new("C063.f90"))]
     #[test_case(Rule::AssumedSize, Path::new("C071.f90"))]
     #[test_case(Rule::AssumedSizeCharacterIntent, Path::new("C072.f90"))]
     #[test_case(Rule::InitialisationInDeclaration, Path::new("C081.f90"))]
@@ -50,6 +64,12 @@ mod tests {
     #[test_case(Rule::MissingAccessibilityStatement, Path::new("C131.f90"))]
     #[test_case(Rule::DefaultPublicAccessibility, Path::new("C132.f90"))]
     #[test_case(Rule::MissingExitOrCycleLabel, Path::new("C141.f90"))]
+    #[test_case(Rule::FloatingPointEquality, Path::new("C151.f90"))]
+    #[test_case(Rule::TruncatedIntegerDivision, Path::new("C161.f90"))]
+    #[test_case(Rule::MixedPrecisionArithmetic, Path::new("C171.f90"))]
+    #[test_case(Rule::NarrowingAssignment, Path::new("C172.f90"))]
+    #[test_case(Rule::MissingAllocateStat, Path::new("C181.f90"))]
+    #[test_case(Rule::UncheckedAllocateStat, Path::new("C182.f90"))]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
         let diagnostics = test_path(
@@ -62,6 +82,56 @@ mod tests {
         Ok(())
     }
 
+    #[test_case(Rule::FloatingPointEquality, Path::new("C151_allow_zero.f90"))]
+    fn floating_point_equality_allow_zero(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+
+        let default = Settings::default();
+        let settings = Settings {
+            check: CheckSettings {
+                floating_point_equality: FloatingPointEqualitySettings { allow_zero: true },
+                ..default.check
+            },
+            ..default
+        };
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        apply_common_filters!();
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::MissingAllocateStat, Path::new("C181.f90"))]
+    fn missing_allocate_stat_exempt_programs(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!(
+            "{}_{}_exempt_programs",
+            rule_code.as_ref(),
+            path.to_string_lossy()
+        );
+
+        let default = Settings::default();
+        let settings = Settings {
+            check: CheckSettings {
+                allocate_stat: AllocateStatSettings {
+                    exempt_programs: true,
+                },
+                ..default.check
+            },
+            ..default
+        };
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        apply_common_filters!();
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
     #[test_case(Rule::ImplicitTyping, Path::new("C001_ok.f90"))]
     #[test_case(Rule::InterfaceImplicitTyping, Path::new("C002_ok.f90"))]
     fn rules_pass(rule_code: Rule, path: &Path) -> Result<()> {
@@ -0,0 +1,176 @@
+use crate::ast::FortitudeNode;
+use crate::rules::correctness::settings::PrecisionSettings;
+use crate::rules::utilities::{numeric_type, strip_unary, NumericType};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// Get the precision in bits of a `real` or `complex` expression. For
+/// arithmetic, this is the widest of the operands, ignoring integers.
+///
+/// Returns `None` for integers, or if the precision of any part of the
+/// expression isn't known.
+fn precision(node: &Node, settings: &PrecisionSettings, src: &str) -> Option<u16> {
+    let node = strip_unary(node);
+    if node.kind() != "math_expression" {
+        return settings.bits(numeric_type(&node, src)?.real_kind()?);
+    }
+
+    let mut widest = None;
+    for operand in [
+        node.child_by_field_name("left")?,
+        node.child_by_field_name("right")?,
+    ] {
+        if numeric_type(&operand, src)? != NumericType::Integer {
+            widest = widest.max(Some(precision(&operand, settings, src)?));
+        }
+    }
+    widest
+}
+
+/// ## What it does
+/// Checks for arithmetic between `real` or `complex` values of different
+/// precisions, such as a `real(real64)` variable multiplied by a default
+/// `real` literal.
+///
+/// ## Why is this bad?
+/// The lower precision operand is converted to the higher precision, but any
+/// precision it has already lost can't be recovered. This is particularly
+/// surprising for literals:
+///
+/// ```f90
+/// real(real64) :: x
+/// x = 0.1 * x
+/// ```
+///
+/// Here `0.1` is a single precision literal, which is only accurate to around
+/// 7 significant figures, so the result is much less accurate than `x`. Use a
+/// literal with the same kind, such as `0.1_real64`, or convert values
+/// explicitly with `real(..., kind)`. This complements `no-real-suffix`
+/// (`C021`), which only checks literals in declarations.
+///
+/// Precisions are worked out from literals, and variables declared in an
+/// enclosing scope. Default `real` is assumed to be 32 bits.
+///
+/// ## Options
+/// - `check.precision.kinds`
+#[derive(ViolationMetadata)]
+pub(crate) struct MixedPrecisionArithmetic {
+    operator: String,
+    left: u16,
+    right: u16,
+}
+
+impl Violation for MixedPrecisionArithmetic {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            operator,
+            left,
+            right,
+        } = self;
+        format!("'{operator}' mixes {left}-bit and {right}-bit precision")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use the same kind for both operands".to_string())
+    }
+}
+
+impl AstRule for MixedPrecisionArithmetic {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let settings = &settings.check.precision;
+        let text = src.source_text();
+
+        let operator = node.child(1)?.to_text(text)?.to_string();
+        let left = precision(&node.child_by_field_name("left")?, settings, text)?;
+        let right = precision(&node.child_by_field_name("right")?, settings, text)?;
+        if left == right {
+            return None;
+        }
+
+        let violation = Self {
+            operator,
+            left,
+            right,
+        };
+        some_vec![Diagnostic::from_node(violation, node)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["math_expression"]
+    }
+}
+
+/// ## What it does
+/// Checks for assignments of a `real` or `complex` expression to a variable
+/// with a lower precision, such as a `real(real64)` expression assigned to a
+/// `real(real32)` variable.
+///
+/// ## Why is this bad?
+/// The value is silently rounded to the precision of the variable. If this is
+/// intended, make it clear with an explicit conversion:
+///
+/// ```f90
+/// real(real32) :: x
+/// real(real64) :: y
+/// x = real(y, real32)
+/// ```
+///
+/// Precisions are worked out from literals, and variables declared in an
+/// enclosing scope. Default `real` is assumed to be 32 bits.
+///
+/// ## Options
+/// - `check.precision.kinds`
+#[derive(ViolationMetadata)]
+pub(crate) struct NarrowingAssignment {
+    name: String,
+    target: u16,
+    value: u16,
+}
+
+impl Violation for NarrowingAssignment {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            name,
+            target,
+            value,
+        } = self;
+        format!("{value}-bit value assigned to {target}-bit variable '{name}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Convert explicitly if the loss of precision is intended".to_string())
+    }
+}
+
+impl AstRule for NarrowingAssignment {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let settings = &settings.check.precision;
+        let text = src.source_text();
+
+        let left = node.child_by_field_name("left")?;
+        let right = node.child_by_field_name("right")?;
+        let target = precision(&left, settings, text)?;
+        let value = precision(&right, settings, text)?;
+        if value <= target {
+            return None;
+        }
+
+        let name = left.to_text(text)?.to_string();
+        let violation = Self {
+            name,
+            target,
+            value,
+        };
+        some_vec![Diagnostic::from_node(violation, &right)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["assignment_statement"]
+    }
+}
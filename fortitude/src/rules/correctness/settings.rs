@@ -0,0 +1,99 @@
+//! Settings for the correctness rules
+
+use std::fmt;
+
+use rustc_hash::FxHashMap;
+
+use crate::display_settings;
+use crate::rules::utilities::RealKind;
+
+/// Settings for [`MissingAllocateStat`](super::allocate_stat::MissingAllocateStat)
+/// and [`UncheckedAllocateStat`](super::allocate_stat::UncheckedAllocateStat)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllocateStatSettings {
+    /// Allow statements in main programs
+    pub exempt_programs: bool,
+}
+
+impl fmt::Display for AllocateStatSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_settings! {
+            formatter = f,
+            namespace = "check.allocate_stat",
+            fields = [
+                self.exempt_programs,
+            ]
+        }
+        Ok(())
+    }
+}
+
+/// Settings for [`FloatingPointEquality`](super::float_comparison::FloatingPointEquality)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FloatingPointEqualitySettings {
+    /// Allow comparisons against an exact zero, such as `x == 0.0`
+    pub allow_zero: bool,
+}
+
+impl fmt::Display for FloatingPointEqualitySettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_settings! {
+            formatter = f,
+            namespace = "check.floating_point_equality",
+            fields = [
+                self.allow_zero,
+            ]
+        }
+        Ok(())
+    }
+}
+
+/// Settings for [`MixedPrecisionArithmetic`](super::precision::MixedPrecisionArithmetic)
+/// and [`NarrowingAssignment`](super::precision::NarrowingAssignment)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrecisionSettings {
+    /// The precision in bits of kind parameters, in addition to those from
+    /// `iso_fortran_env` and `iso_c_binding`. Names are lowercase.
+    pub kinds: FxHashMap<String, u16>,
+}
+
+impl Default for PrecisionSettings {
+    fn default() -> Self {
+        Self {
+            kinds: [("sp", 32), ("dp", 64), ("qp", 128)]
+                .into_iter()
+                .map(|(name, bits)| (name.to_string(), bits))
+                .collect(),
+        }
+    }
+}
+
+impl PrecisionSettings {
+    /// Get the precision in bits of a kind, if known. Default `real` is
+    /// assumed to be single precision.
+    pub fn bits(&self, kind: &RealKind) -> Option<u16> {
+        match kind {
+            RealKind::Default => Some(32),
+            RealKind::Double => Some(64),
+            RealKind::Named(name) => match name.as_str() {
+                "real32" | "c_float" => Some(32),
+                "real64" | "c_double" => Some(64),
+                "real128" => Some(128),
+                _ => self.kinds.get(name).copied(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for PrecisionSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_settings! {
+            formatter = f,
+            namespace = "check.precision",
+            fields = [
+                self.kinds | map,
+            ]
+        }
+        Ok(())
+    }
+}
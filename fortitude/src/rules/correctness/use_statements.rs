@@ -74,7 +74,7 @@ impl AstRule for UseAll {
 #[derive(ViolationMetadata)]
 pub(crate) struct MissingIntrinsic {}
 
-const INTRINSIC_MODULES: &[&str] = &[
+pub(crate) const INTRINSIC_MODULES: &[&str] = &[
     "iso_fortran_env",
     "iso_c_binding",
     "ieee_exceptions",
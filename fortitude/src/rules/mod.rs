@@ -83,8 +83,14 @@ pub fn code_to_rule(category: Category, code: &str) -> Option<(RuleGroup, Rule)>
         (Correctness, "031") => (RuleGroup::Preview, Ast, Optional, correctness::magic_numbers::MagicNumberInArraySize),
         (Correctness, "032") => (RuleGroup::Preview, Ast, Optional, correctness::magic_numbers::MagicIoUnit),
         (Correctness, "043") => (RuleGroup::Preview, Ast, Optional, correctness::missing_io_specifier::MissingActionSpecifier),
+        (Correctness, "044") => (RuleGroup::Preview, Ast, Optional, correctness::missing_io_specifier::MissingIostat),
+        (Correctness, "045") => (RuleGroup::Preview, Ast, Optional, correctness::missing_io_specifier::UncheckedIostat),
+        (Correctness, "046") => (RuleGroup::Preview, Ast, Optional, correctness::missing_io_specifier::MissingStatusSpecifier),
+        (Correctness, "047") => (RuleGroup::Preview, Ast, Optional, correctness::missing_io_specifier::LiteralUnitInOpen),
         (Correctness, "051") => (RuleGroup::Preview, Ast, Default, correctness::trailing_backslash::TrailingBackslash),
         (Correctness, "061") => (RuleGroup::Stable, Ast, Default, correctness::intent::MissingIntent),
+        (Correctness, "062") => (RuleGroup::Preview, Ast, Default, correctness::intent::ModifiedIntentIn),
+        (Correctness, "063") => (RuleGroup::Preview, Ast, Default, correctness::intent::UnassignedIntentOut),
         (Correctness, "071") => (RuleGroup::Stable, Ast, Default, correctness::assumed_size::AssumedSize),
         (Correctness, "072") => (RuleGroup::Stable, Ast, Default, correctness::assumed_size::AssumedSizeCharacterIntent),
         (Correctness, "081") => (RuleGroup::Stable, Ast, Default, correctness::init_decls::InitialisationInDeclaration),
@@ -96,6 +102,12 @@ pub fn code_to_rule(category: Category, code: &str) -> Option<(RuleGroup, Rule)>
         (Correctness, "131") => (RuleGroup::Stable, Ast, Default, correctness::accessibility_statements::MissingAccessibilityStatement),
         (Correctness, "132") => (RuleGroup::Stable, Ast, Optional, correctness::accessibility_statements::DefaultPublicAccessibility),
         (Correctness, "141") => (RuleGroup::Stable, Ast, Default, correctness::exit_labels::MissingExitOrCycleLabel),
+        (Correctness, "151") => (RuleGroup::Preview, Ast, Default, correctness::float_comparison::FloatingPointEquality),
+        (Correctness, "161") => (RuleGroup::Preview, Ast, Default, correctness::integer_division::TruncatedIntegerDivision),
+        (Correctness, "171") => (RuleGroup::Preview, Ast, Optional, correctness::precision::MixedPrecisionArithmetic),
+        (Correctness, "172") => (RuleGroup::Preview, Ast, Default, correctness::precision::NarrowingAssignment),
+        (Correctness, "181") => (RuleGroup::Preview, Ast, Optional, correctness::allocate_stat::MissingAllocateStat),
+        (Correctness, "182") => (RuleGroup::Preview, Ast, Optional, correctness::allocate_stat::UncheckedAllocateStat),
         
         (Modernisation, "001") => (RuleGroup::Stable, Ast, Optional, modernisation::double_precision::DoublePrecision),
         (Modernisation, "011") => (RuleGroup::Stable, Ast, Default, modernisation::old_style_array_literal::OldStyleArrayLiteral),
@@ -106,6 +118,7 @@ pub fn code_to_rule(category: Category, code: &str) -> Option<(RuleGroup, Rule)>
         (Portability, "011") => (RuleGroup::Stable, Ast, Default, portability::literal_kinds::LiteralKind),
         (Portability, "012") => (RuleGroup::Stable, Ast, Default, portability::literal_kinds::LiteralKindSuffix),
         (Portability, "021") => (RuleGroup::Stable, Ast, Default, portability::star_kinds::StarKind),
+        (Portability, "031") => (RuleGroup::Preview, Ast, Optional, portability::real_kinds::UnapprovedRealKind),
 
         (Style, "001") => (RuleGroup::Stable, Text, Default, style::line_length::LineTooLong),
         (Style, "061") => (RuleGroup::Stable, Ast, Default, style::end_statements::UnnamedEndStatement),
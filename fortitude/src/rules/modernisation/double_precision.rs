@@ -1,9 +1,13 @@
 use crate::ast::FortitudeNode;
+use crate::fix::edits::{enclosing_scopes, ensure_use_statement};
+use crate::rules::modernisation::settings::DoublePrecisionSettings;
 use crate::settings::Settings;
 use crate::{AstRule, FromAstNode};
-use ruff_diagnostics::{Diagnostic, Violation};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, ViolationMetadata};
 use ruff_source_file::SourceFile;
+use ruff_text_size::TextSize;
 use tree_sitter::Node;
 
 // TODO rule to prefer 1.23e4_sp over 1.23e4, and 1.23e4_dp over 1.23d4
@@ -27,6 +31,22 @@ use tree_sitter::Node;
 /// For code that should be compatible with C, you should instead use
 /// `real(c_double)`, which may be found in the intrinsic module `iso_c_binding`.
 ///
+/// ## Fix
+/// The fix replaces the type with `real(real64)` or `complex(real64)`, and
+/// imports `real64` from `iso_fortran_env` if it isn't already available. So
+/// that the rest of the scope is consistent, it also replaces calls to `dble(x)`
+/// and `dfloat(x)` with `real(x, real64)`, and literals with a `d` exponent,
+/// such as `1.0d0`, with `1.0_real64`.
+///
+/// Projects that define their own kind parameters can set the kind and the
+/// module it comes from. The fix is only safe for the default `real64` from
+/// `iso_fortran_env`, as other kinds may change the precision of every
+/// conversion and literal rewritten in the scope.
+///
+/// ## Options
+/// - `check.double-precision.kind`
+/// - `check.double-precision.kind-module`
+///
 /// ## References
 /// - Metcalf, M., Reid, J. and Cohen, M., 2018, _Modern Fortran Explained: Incorporating Fortran
 ///   2018_, Oxford University Press, Appendix A 'Deprecated Features'
@@ -35,42 +55,144 @@ use tree_sitter::Node;
 pub(crate) struct DoublePrecision {
     original: String, // TODO: could be &'static str
     preferred: String,
+    module: String,
 }
 
 impl DoublePrecision {
-    fn try_new<S: AsRef<str>>(original: S) -> Option<Self> {
-        match original.as_ref() {
-            "double precision" => Some(Self {
-                original: original.as_ref().to_string(),
-                preferred: "real(real64)".to_string(),
-            }),
-            "double complex" => Some(Self {
-                original: original.as_ref().to_string(),
-                preferred: "complex(real64)".to_string(),
-            }),
-            _ => None,
-        }
+    fn try_new<S: AsRef<str>>(original: S, settings: &DoublePrecisionSettings) -> Option<Self> {
+        let dtype = match original.as_ref() {
+            "double precision" => "real",
+            "double complex" => "complex",
+            _ => return None,
+        };
+        Some(Self {
+            original: original.as_ref().to_string(),
+            preferred: format!("{dtype}({})", settings.kind),
+            module: settings.kind_module.clone(),
+        })
     }
 }
 
 impl Violation for DoublePrecision {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let DoublePrecision {
             original,
             preferred,
+            module,
         } = self;
-        format!("prefer '{preferred}' to '{original}' (see 'iso_fortran_env')")
+        format!("prefer '{preferred}' to '{original}' (see '{module}')")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { preferred, .. } = self;
+        Some(format!("Replace with '{preferred}'"))
     }
 }
 
 impl AstRule for DoublePrecision {
-    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let settings = &settings.check.double_precision;
         let txt = node.to_text(src.source_text())?.to_lowercase();
-        some_vec![Diagnostic::from_node(DoublePrecision::try_new(txt)?, node)]
+        let violation = DoublePrecision::try_new(txt, settings)?;
+        let replacement = node.edit_replacement(src, violation.preferred.clone());
+        let diagnostic = Diagnostic::from_node(violation, node);
+
+        let Some(use_edits) =
+            ensure_use_statement(node, &settings.kind_module, &settings.kind, src)
+        else {
+            return some_vec![diagnostic];
+        };
+        let scope_edits = enclosing_scopes(node)
+            .first()
+            .map(|scope| double_precision_usages(scope, &settings.kind, src))
+            .unwrap_or_default();
+
+        let edits = use_edits.into_iter().chain(scope_edits);
+        let fix = if settings.is_real64() {
+            Fix::safe_edits(replacement, edits)
+        } else {
+            Fix::unsafe_edits(replacement, edits)
+        };
+        some_vec![diagnostic.with_fix(fix)]
     }
 
     fn entrypoints() -> Vec<&'static str> {
         vec!["intrinsic_type"]
     }
 }
+
+/// Get the edits replacing calls to `dble` and `dfloat`, and literals with a
+/// `d` exponent, in a scope, ignoring any contained procedures
+fn double_precision_usages(scope: &Node, kind: &str, src: &SourceFile) -> Vec<Edit> {
+    let text = src.source_text();
+    scope
+        .named_descendants_except(["internal_procedures"])
+        .filter_map(|node| match node.kind() {
+            "number_literal" => {
+                let literal = with_kind_suffix(node.to_text(text)?, kind)?;
+                Some(vec![node.edit_replacement(src, literal)])
+            }
+            "call_expression" => double_conversion_edits(&node, kind, src),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// Rewrite a literal with a `d` exponent, like `1.5d3`, to use a kind suffix
+/// instead, like `1.5e3_real64`
+pub(crate) fn with_kind_suffix(literal: &str, kind: &str) -> Option<String> {
+    let (_, mantissa, exponent) = regex_captures!(r"^(\d+\.?\d*|\.\d+)[dD]([+-]?\d+)$", literal)?;
+    if exponent
+        .trim_start_matches(['+', '-'])
+        .chars()
+        .all(|c| c == '0')
+    {
+        if mantissa.contains('.') {
+            Some(format!("{mantissa}_{kind}"))
+        } else {
+            Some(format!("{mantissa}.0_{kind}"))
+        }
+    } else {
+        Some(format!("{mantissa}e{exponent}_{kind}"))
+    }
+}
+
+/// Get the edits rewriting `dble(x)` or `dfloat(x)` to `real(x, kind)`
+fn double_conversion_edits(node: &Node, kind: &str, src: &SourceFile) -> Option<Vec<Edit>> {
+    let function = node.named_child(0)?;
+    if function.kind() != "identifier"
+        || !matches!(
+            function.to_text(src.source_text())?.to_lowercase().as_str(),
+            "dble" | "dfloat"
+        )
+    {
+        return None;
+    }
+
+    let arguments = node.child_with_name("argument_list")?;
+    let mut args = arguments.named_children(&mut arguments.walk());
+    let (Some(arg), None) = (args.next(), args.next()) else {
+        return None;
+    };
+    if arg.kind() == "keyword_argument" {
+        return None;
+    }
+
+    // Edit the name and closing bracket separately, so that any conversions
+    // or literals in the argument can be rewritten too
+    let close = arguments.child(arguments.child_count().checked_sub(1)?)?;
+    if close.kind() != ")" {
+        return None;
+    }
+    Some(vec![
+        function.edit_replacement(src, "real".to_string()),
+        Edit::insertion(
+            format!(", {kind}"),
+            TextSize::try_from(close.start_byte()).unwrap(),
+        ),
+    ])
+}
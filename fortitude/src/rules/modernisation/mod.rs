@@ -2,6 +2,7 @@ pub mod double_precision;
 pub mod include_statement;
 pub mod old_style_array_literal;
 pub mod relational_operators;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -14,7 +15,8 @@ mod tests {
 
     use crate::apply_common_filters;
     use crate::registry::Rule;
-    use crate::settings::Settings;
+    use crate::rules::modernisation::settings::DoublePrecisionSettings;
+    use crate::settings::{CheckSettings, Settings};
     use crate::test::test_path;
 
     #[test_case(Rule::DoublePrecision, Path::new("MOD001.f90"))]
@@ -32,4 +34,29 @@ mod tests {
         assert_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test_case(Rule::DoublePrecision, Path::new("MOD001_kind_dp.f90"))]
+    fn double_precision_kind_dp(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+
+        let default = Settings::default();
+        let settings = Settings {
+            check: CheckSettings {
+                double_precision: DoublePrecisionSettings {
+                    kind: "dp".to_string(),
+                    kind_module: "kinds".to_string(),
+                },
+                ..default.check
+            },
+            ..default
+        };
+        let diagnostics = test_path(
+            Path::new("modernisation").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        apply_common_filters!();
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
 }
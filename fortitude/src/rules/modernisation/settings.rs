@@ -0,0 +1,46 @@
+//! Settings for the modernisation rules
+
+use std::fmt;
+
+use crate::display_settings;
+
+/// Settings for [`DoublePrecision`](super::double_precision::DoublePrecision)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoublePrecisionSettings {
+    /// The kind parameter used in fixes
+    pub kind: String,
+    /// The module that `kind` is imported from
+    pub kind_module: String,
+}
+
+impl Default for DoublePrecisionSettings {
+    fn default() -> Self {
+        Self {
+            kind: "real64".to_string(),
+            kind_module: "iso_fortran_env".to_string(),
+        }
+    }
+}
+
+impl DoublePrecisionSettings {
+    /// Whether fixes use `real64` from `iso_fortran_env`, which matches the
+    /// precision of `double precision` on all common platforms
+    pub fn is_real64(&self) -> bool {
+        self.kind.eq_ignore_ascii_case("real64")
+            && self.kind_module.eq_ignore_ascii_case("iso_fortran_env")
+    }
+}
+
+impl fmt::Display for DoublePrecisionSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_settings! {
+            formatter = f,
+            namespace = "check.double_precision",
+            fields = [
+                self.kind | quoted,
+                self.kind_module | quoted,
+            ]
+        }
+        Ok(())
+    }
+}
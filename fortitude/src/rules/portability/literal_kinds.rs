@@ -1,13 +1,14 @@
 use crate::ast::{dtype_is_plain_number, FortitudeNode};
+use crate::fix::edits::ensure_use_statement;
 use crate::settings::Settings;
 use crate::{AstRule, FromAstNode};
 use lazy_regex::regex_is_match;
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, ViolationMetadata};
 use ruff_source_file::SourceFile;
 use tree_sitter::Node;
 
-fn iso_fortran_env_param<S: AsRef<str>>(dtype: S, literal: u8) -> Option<String> {
+pub(crate) fn iso_fortran_env_param<S: AsRef<str>>(dtype: S, literal: u8) -> Option<String> {
     match dtype.as_ref() {
         "integer" | "logical" => {
             if matches!(literal, 1u8 | 2u8 | 4u8 | 8u8) {
@@ -83,6 +84,13 @@ fn iso_fortran_env_param<S: AsRef<str>>(dtype: S, literal: u8) -> Option<String>
 ///                                          i4 => int32, &
 ///                                          i8 => int64
 /// ```
+///
+/// ## Fix
+/// Where there is an equivalent parameter in `iso_fortran_env`, the fix
+/// replaces the literal with it, such as `real(8)` to `real(real64)`, and
+/// imports the parameter if it isn't already available.
+///
+/// As with `star-kind` (`PORT021`), the fix is unsafe for `logical`.
 #[derive(ViolationMetadata)]
 pub(crate) struct LiteralKind {
     dtype: String,
@@ -90,6 +98,8 @@ pub(crate) struct LiteralKind {
 }
 
 impl Violation for LiteralKind {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
     #[derive_message_formats]
     fn message(&self) -> String {
         let Self { dtype, literal } = self;
@@ -104,8 +114,8 @@ impl Violation for LiteralKind {
 }
 
 impl AstRule for LiteralKind {
-    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
-        let src = src.source_text();
+    fn check(_settings: &Settings, node: &Node, file: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let src = file.source_text();
         let dtype = node.child(0)?.to_text(src)?.to_lowercase();
         // TODO: Deal with characters
         if !dtype_is_plain_number(dtype.as_str()) {
@@ -115,10 +125,29 @@ impl AstRule for LiteralKind {
         let kind_node = node.child_by_field_name("kind")?;
         let literal_node = integer_literal_kind(&kind_node, src)?;
         let literal: u8 = literal_node.to_text(src)?.parse().ok()?;
-        some_vec![Diagnostic::from_node(
-            Self { dtype, literal },
-            &literal_node
-        )]
+        let param = iso_fortran_env_param(&dtype, literal);
+        // `iso_fortran_env` only has parameters for `integer` kinds, which
+        // `logical` kinds needn't match
+        let is_logical = dtype == "logical";
+        let diagnostic = Diagnostic::from_node(Self { dtype, literal }, &literal_node);
+
+        // Star kinds like `logical*4` are fixed by `StarKind` instead
+        let is_star_kind = kind_node.to_text(src)?.starts_with('*');
+        let Some(param) = param.filter(|_| !is_star_kind) else {
+            return some_vec![diagnostic];
+        };
+        match ensure_use_statement(node, "iso_fortran_env", &param, file) {
+            Some(use_edits) => {
+                let replacement = literal_node.edit_replacement(file, param);
+                let fix = if is_logical {
+                    Fix::unsafe_edits(replacement, use_edits)
+                } else {
+                    Fix::safe_edits(replacement, use_edits)
+                };
+                some_vec![diagnostic.with_fix(fix)]
+            }
+            None => some_vec![diagnostic],
+        }
     }
 
     fn entrypoints() -> Vec<&'static str> {
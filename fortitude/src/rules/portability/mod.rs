@@ -1,5 +1,7 @@
 pub mod literal_kinds;
 pub mod magic_io_unit;
+pub mod real_kinds;
+pub mod settings;
 pub mod star_kinds;
 
 #[cfg(test)]
@@ -13,7 +15,8 @@ mod tests {
 
     use crate::apply_common_filters;
     use crate::registry::Rule;
-    use crate::settings::Settings;
+    use crate::rules::portability::settings::UnapprovedRealKindSettings;
+    use crate::settings::{CheckSettings, Settings};
     use crate::test::test_path;
 
     #[test_case(Rule::NonPortableIoUnit, Path::new("PORT001.f90"))]
@@ -31,4 +34,29 @@ mod tests {
         assert_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test_case(Rule::UnapprovedRealKind, Path::new("PORT031.f90"))]
+    fn unapproved_real_kind_kind_module(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+
+        let default = Settings::default();
+        let settings = Settings {
+            check: CheckSettings {
+                unapproved_real_kind: UnapprovedRealKindSettings {
+                    real_kinds: vec!["wp".to_string()],
+                    kind_module: Some("precision_mod".to_string()),
+                },
+                ..default.check
+            },
+            ..default
+        };
+        let diagnostics = test_path(
+            Path::new("portability").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        apply_common_filters!();
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
 }
@@ -0,0 +1,159 @@
+use crate::ast::FortitudeNode;
+use crate::fix::edits::ensure_use_statement;
+use crate::rules::modernisation::double_precision::with_kind_suffix;
+use crate::rules::utilities::kind_value;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `real` and `complex` declarations and literals whose kind isn't
+/// one of a project's approved kind parameters.
+///
+/// ## Why is this bad?
+/// Many projects set the precision of their floating point numbers in a single
+/// module, often as a 'working precision' `wp`:
+///
+/// ```f90
+/// module precision_mod
+///   use, intrinsic :: iso_fortran_env, only: real64
+///   integer, parameter :: wp = real64
+/// end module precision_mod
+/// ```
+///
+/// This makes it easy to change the precision of the whole project at once.
+/// Any declaration that uses a different kind, such as `real(real64)`,
+/// `real(8)`, `real(kind=dp)` or `double precision`, or any literal such as
+/// `1.0_dp` or `1.0d0`, won't follow that change, and may lead to mixed
+/// precision arithmetic or loss of precision.
+///
+/// This rule is turned off by default, and is intended for projects with such
+/// a policy. It complements `literal-kind` (`PORT011`), which catches kinds set
+/// with number literals, and `implicit-real-kind` (`C022`), which catches
+/// declarations with no kind at all.
+///
+/// ## Fix
+/// If `check.unapproved-real-kind.kind-module` is set, the fix replaces the
+/// kind with the first of the approved kinds, and imports it from that module
+/// if it isn't already available. For example, `double precision` becomes
+/// `real(wp)`, and `1.5d3` becomes `1.5e3_wp`. The fix is unsafe, as it may
+/// change the precision of the variable or literal.
+///
+/// ## Options
+/// - `check.unapproved-real-kind.real-kinds`
+/// - `check.unapproved-real-kind.kind-module`
+#[derive(ViolationMetadata)]
+pub(crate) struct UnapprovedRealKind {
+    dtype: String,
+    kind: String,
+    preferred: Option<String>,
+}
+
+impl Violation for UnapprovedRealKind {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { dtype, kind, .. } = self;
+        format!("{dtype} has unapproved kind '{kind}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { preferred, .. } = self;
+        preferred
+            .as_ref()
+            .map(|preferred| format!("Replace with '{preferred}'"))
+    }
+}
+
+impl AstRule for UnapprovedRealKind {
+    fn check(settings: &Settings, node: &Node, file: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let settings = &settings.check.unapproved_real_kind;
+        let preferred = settings.real_kinds.first()?;
+        let src = file.source_text();
+
+        // The type and its kind, the node to report, and the node to replace
+        // to fix it along with its replacement
+        let (dtype, kind, value, replaced, replacement) = match node.kind() {
+            "intrinsic_type" => {
+                let text = node.to_text(src)?.to_lowercase();
+                // These have no kind spec, so replace the whole type
+                if let Some(dtype) = double_precision_dtype(&text) {
+                    let replacement = format!("{dtype}({preferred})");
+                    (dtype.to_string(), text, *node, *node, replacement)
+                } else {
+                    let dtype = node.child(0)?.to_text(src)?.to_lowercase();
+                    if !matches!(dtype.as_str(), "real" | "complex") {
+                        return None;
+                    }
+                    // Replace the whole kind spec, so that `real*8` becomes `real(wp)`
+                    let kind_node = node.child_by_field_name("kind")?;
+                    let value = kind_value(&kind_node, src)?;
+                    let kind = value.to_text(src)?.to_string();
+                    (dtype, kind, value, kind_node, format!("({preferred})"))
+                }
+            }
+            _ => {
+                let dtype = "real literal".to_string();
+                let text = node.to_text(src)?;
+                // Literals with a `d` exponent, like `1.0d0`, are double precision
+                if let Some(replacement) = with_kind_suffix(text, preferred) {
+                    let kind = "double precision".to_string();
+                    (dtype, kind, *node, *node, replacement)
+                } else {
+                    let kind_node = node.child_by_field_name("kind")?;
+                    let number = text.split('_').next()?;
+                    if !regex_is_match!(r"^(\d*\.\d*|\d*\.?\d*[eE][+-]?\d+)$", number) {
+                        return None;
+                    }
+                    let kind = kind_node.to_text(src)?.to_string();
+                    (dtype, kind, kind_node, kind_node, preferred.to_string())
+                }
+            }
+        };
+
+        if settings
+            .real_kinds
+            .iter()
+            .any(|approved| approved.eq_ignore_ascii_case(&kind))
+        {
+            return None;
+        }
+
+        let use_edits = settings
+            .kind_module
+            .as_ref()
+            .and_then(|module| ensure_use_statement(node, module, preferred, file));
+        let violation = Self {
+            dtype,
+            kind,
+            preferred: use_edits.as_ref().map(|_| preferred.to_string()),
+        };
+        let diagnostic = Diagnostic::from_node(violation, &value);
+
+        match use_edits {
+            Some(use_edits) => {
+                let edit = replaced.edit_replacement(file, replacement);
+                some_vec![diagnostic.with_fix(Fix::unsafe_edits(edit, use_edits))]
+            }
+            None => some_vec![diagnostic],
+        }
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["intrinsic_type", "number_literal"]
+    }
+}
+
+/// The type that `double precision` or `double complex` is equivalent to
+fn double_precision_dtype(text: &str) -> Option<&'static str> {
+    match text {
+        "double precision" => Some("real"),
+        "double complex" => Some("complex"),
+        _ => None,
+    }
+}
@@ -0,0 +1,38 @@
+//! Settings for the portability rules
+
+use std::fmt;
+
+use crate::display_settings;
+
+/// Settings for [`UnapprovedRealKind`](super::real_kinds::UnapprovedRealKind)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnapprovedRealKindSettings {
+    /// The named constants that may be used as `real` and `complex` kinds.
+    /// Fixes use the first of these
+    pub real_kinds: Vec<String>,
+    /// The module that the kinds are imported from, if known
+    pub kind_module: Option<String>,
+}
+
+impl Default for UnapprovedRealKindSettings {
+    fn default() -> Self {
+        Self {
+            real_kinds: vec!["wp".to_string()],
+            kind_module: None,
+        }
+    }
+}
+
+impl fmt::Display for UnapprovedRealKindSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_settings! {
+            formatter = f,
+            namespace = "check.unapproved_real_kind",
+            fields = [
+                self.real_kinds | array,
+                self.kind_module | optional,
+            ]
+        }
+        Ok(())
+    }
+}
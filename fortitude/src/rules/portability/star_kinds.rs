@@ -1,4 +1,6 @@
 use crate::ast::{dtype_is_plain_number, strip_line_breaks, FortitudeNode};
+use crate::fix::edits::ensure_use_statement;
+use crate::rules::portability::literal_kinds::iso_fortran_env_param;
 use crate::settings::Settings;
 use crate::{AstRule, FromAstNode};
 use ruff_diagnostics::{Diagnostic, Fix, FixAvailability, Violation};
@@ -16,14 +18,21 @@ use tree_sitter::Node;
 /// module 'iso_fortran_env'. You may also wish to determine kinds using the
 /// built-in functions 'selected_real_kind' and 'selected_int_kind'.
 ///
-/// Fixes to this rule are considered unsafe, as while `dtype*N` is generally
-/// understood to mean a `dtype` that occupied `N` bytes, this does not necessarily
-/// correspond to `dtype(N)`, which is a `dtype` of 'kind' `N`. For example, the NAG
-/// compiler may be configured to use a sequential kind system in which `real*8`
-/// corresponds to `real(2)`
+/// ## Fix
+/// As `dtype*N` is generally understood to mean a `dtype` that occupies `N`
+/// bytes, the fix replaces it with the matching parameter from
+/// `iso_fortran_env`, such as `real*8` to `real(real64)` or `complex*16` to
+/// `complex(real64)`, and imports the parameter if it isn't already available.
 ///
-/// In a future version, we hope to upgrade this to a safe fix by use of parameters
-/// in `iso_fortran_env`, as `real*8` should always correspond to `real(real64)`.
+/// Where there is no matching parameter, the fix replaces `dtype*N` with
+/// `dtype(N)`. This is considered unsafe, as `dtype(N)` is a `dtype` of 'kind'
+/// `N`, which doesn't necessarily occupy `N` bytes. For example, the NAG
+/// compiler may be configured to use a sequential kind system in which
+/// `real*8` corresponds to `real(2)`.
+///
+/// The fix for `logical` is also unsafe, as `iso_fortran_env` only has
+/// parameters for `integer` kinds, and there's no guarantee that, for example,
+/// `logical(int32)` occupies 4 bytes.
 #[derive(ViolationMetadata)]
 pub(crate) struct StarKind {
     dtype: String,
@@ -65,8 +74,32 @@ impl AstRule for StarKind {
 
         let literal = kind_node.child_with_name("number_literal")?;
         let kind = literal.to_text(text)?.to_string();
-        let replacement = format!("{dtype}({kind})");
-        let fix = Fix::unsafe_edit(node.edit_replacement(src, replacement));
+
+        // `complex*16` is made up of two 8 byte reals
+        let bytes = kind.parse::<u8>().ok().map(|bytes| match dtype.as_str() {
+            "complex" => bytes / 2,
+            _ => bytes,
+        });
+        let param = bytes.and_then(|bytes| iso_fortran_env_param(&dtype, bytes));
+        let use_edits = param
+            .as_ref()
+            .and_then(|param| ensure_use_statement(node, "iso_fortran_env", param, src));
+
+        let (kind, fix) = match (param, use_edits) {
+            (Some(param), Some(use_edits)) => {
+                let replacement = node.edit_replacement(src, format!("{dtype}({param})"));
+                let fix = if dtype == "logical" {
+                    Fix::unsafe_edits(replacement, use_edits)
+                } else {
+                    Fix::safe_edits(replacement, use_edits)
+                };
+                (param, fix)
+            }
+            _ => {
+                let replacement = node.edit_replacement(src, format!("{dtype}({kind})"));
+                (kind, Fix::unsafe_edit(replacement))
+            }
+        };
         some_vec![Diagnostic::from_node(Self { dtype, size, kind }, &kind_node).with_fix(fix)]
     }
 
@@ -1,4 +1,6 @@
 use crate::ast::{is_keyword_argument, FortitudeNode};
+use crate::fix::edits::enclosing_scopes;
+use lazy_regex::regex_captures;
 use ruff_source_file::SourceFile;
 use tree_sitter::Node;
 
@@ -27,3 +29,319 @@ pub fn literal_as_io_unit<'a>(node: &'a Node, src: &SourceFile) -> Option<Node<'
         None
     }
 }
+
+/// Get the value of the keyword argument `keyword`, such as `stat=` or
+/// `iostat=`, passed to a statement
+pub fn keyword_value<'a>(node: &Node<'a>, keyword: &str, src: &str) -> Option<Node<'a>> {
+    node.named_children(&mut node.walk())
+        .find(|arg| is_keyword_argument(arg, keyword, src))
+        .and_then(|arg| arg.child_by_field_name("value"))
+}
+
+/// Is the status variable `name`, such as from `stat=name` or `iostat=name`,
+/// read by a statement after `statement` in the same block, or by the condition
+/// of an enclosing `do while` loop, before it is overwritten. Dummy arguments
+/// are assumed to be checked by the caller
+pub fn is_status_checked(statement: &Node, name: &str, src: &str) -> bool {
+    if is_dummy_argument(statement, name, src) {
+        return true;
+    }
+    let mut sibling = statement.next_named_sibling();
+    while let Some(statement) = sibling {
+        match first_use(&statement, name, src) {
+            Some(VariableUse::Read) => return true,
+            Some(VariableUse::Overwrite) => return false,
+            None => sibling = statement.next_named_sibling(),
+        }
+    }
+    // At the end of a `do while` loop body, the status may be read by the
+    // loop condition on the next iteration
+    is_in_loop_condition(statement, name, src)
+}
+
+/// Is `name` read by the condition of a `do while` loop containing `node`
+fn is_in_loop_condition(node: &Node, name: &str, src: &str) -> bool {
+    node.ancestors()
+        .take_while(|ancestor| !matches!(ancestor.kind(), "function" | "subroutine" | "program"))
+        .filter(|ancestor| ancestor.kind() == "do_loop_statement")
+        .filter_map(|do_loop| do_loop.child_with_name("while_statement"))
+        .any(|condition| matches!(first_use(&condition, name, src), Some(VariableUse::Read)))
+}
+
+/// Is `name` a dummy argument of the procedure containing `node`
+fn is_dummy_argument(node: &Node, name: &str, src: &str) -> bool {
+    node.ancestors()
+        .find(|ancestor| matches!(ancestor.kind(), "function" | "subroutine"))
+        .and_then(|procedure| procedure.named_child(0))
+        .and_then(|statement| statement.child_by_field_name("parameters"))
+        .is_some_and(|parameters| {
+            parameters
+                .named_children(&mut parameters.walk())
+                .any(|parameter| {
+                    parameter
+                        .to_text(src)
+                        .is_some_and(|text| text.eq_ignore_ascii_case(name))
+                })
+        })
+}
+
+/// How a variable is used by a statement
+enum VariableUse {
+    Read,
+    Overwrite,
+}
+
+/// Find the first use of the variable `name` in `statement`
+fn first_use(statement: &Node, name: &str, src: &str) -> Option<VariableUse> {
+    statement
+        .named_descendants()
+        .filter(|node| node.kind() == "identifier")
+        .filter(|node| {
+            node.to_text(src)
+                .is_some_and(|text| text.eq_ignore_ascii_case(name))
+        })
+        .map(|node| {
+            let Some(parent) = node.parent() else {
+                return VariableUse::Read;
+            };
+            let overwritten = match parent.kind() {
+                "assignment_statement" => parent.child_by_field_name("left") == Some(node),
+                // Another status, such as `stat=` or `iostat=`, in a later statement
+                "keyword_argument" => {
+                    parent.child_by_field_name("value") == Some(node)
+                        && ["stat", "iostat"]
+                            .iter()
+                            .any(|keyword| is_keyword_argument(&parent, keyword, src))
+                }
+                _ => false,
+            };
+            if overwritten {
+                VariableUse::Overwrite
+            } else {
+                VariableUse::Read
+            }
+        })
+        .next()
+}
+
+/// Get the name of the variable in a declarator, such as `x` in `x(3) = 1`
+pub fn declarator_name<'a>(declarator: &Node, src: &'a str) -> Option<&'a str> {
+    let mut node = *declarator;
+    while node.kind() != "identifier" {
+        node = node
+            .child_by_field_name("left")
+            .or_else(|| node.named_child(0))?;
+    }
+    node.to_text(src)
+}
+
+/// Get the `variable_declaration` of `name` from the scopes enclosing `node`,
+/// innermost first. Only declarations directly in those scopes are found, so
+/// variables from `use` statements and implicitly typed variables aren't.
+pub fn variable_declaration<'a>(node: &'a Node, name: &str, src: &str) -> Option<Node<'a>> {
+    enclosing_scopes(node).into_iter().find_map(|scope| {
+        scope
+            .named_children(&mut scope.walk())
+            .filter(|child| child.kind() == "variable_declaration")
+            .find(|declaration| {
+                declaration
+                    .children_by_field_name("declarator", &mut declaration.walk())
+                    .filter_map(|declarator| declarator_name(&declarator, src))
+                    .any(|declared| declared.eq_ignore_ascii_case(name))
+            })
+    })
+}
+
+/// Get the value of a kind spec, such as `8` in `*8`, `(8)` or `(kind=8)`
+pub fn kind_value<'a>(node: &Node<'a>, src: &str) -> Option<Node<'a>> {
+    if node.to_text(src)?.starts_with('*') {
+        return node.named_child(0);
+    }
+
+    node.named_children(&mut node.walk())
+        .find_map(|child| match child.kind() {
+            "keyword_argument" if is_keyword_argument(&child, "kind", src) => {
+                child.child_by_field_name("value")
+            }
+            "keyword_argument" => None,
+            _ => Some(child),
+        })
+}
+
+/// The kind of a `real` or `complex` value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RealKind {
+    /// No kind given, such as `real :: x` or `1.0`
+    Default,
+    /// `double precision`, `double complex`, or a literal like `1.0d0`
+    Double,
+    /// A kind parameter or number, in lowercase, such as `real64` in
+    /// `real(real64)` or `1.0_real64`
+    Named(String),
+}
+
+impl RealKind {
+    /// Get the suffix that gives a `real` literal this kind
+    pub fn literal_suffix(&self) -> String {
+        match self {
+            Self::Default => String::new(),
+            Self::Double => "d0".to_string(),
+            Self::Named(kind) => format!("_{kind}"),
+        }
+    }
+}
+
+/// The type of a numeric expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumericType {
+    Integer,
+    Real(RealKind),
+    Complex(RealKind),
+}
+
+impl NumericType {
+    /// Is this a `real` or `complex` type
+    pub fn is_floating_point(&self) -> bool {
+        matches!(self, Self::Real(_) | Self::Complex(_))
+    }
+
+    /// Get the kind of a `real` or `complex` type
+    pub fn real_kind(&self) -> Option<&RealKind> {
+        match self {
+            Self::Integer => None,
+            Self::Real(kind) | Self::Complex(kind) => Some(kind),
+        }
+    }
+}
+
+/// Skip over unary operators and parentheses, so that `-(1.0)` gives `1.0`
+pub fn strip_unary<'a>(node: &Node<'a>) -> Node<'a> {
+    let mut node = *node;
+    while matches!(node.kind(), "unary_expression" | "parenthesized_expression") {
+        match node.named_child(node.named_child_count().saturating_sub(1)) {
+            Some(child) => node = child,
+            None => break,
+        }
+    }
+    node
+}
+
+/// Get the type of a number literal, such as `1`, `1.0e3_wp` or `1.0d0`
+pub fn literal_type(literal: &str) -> NumericType {
+    let (number, suffix) = match literal.split_once('_') {
+        Some((number, suffix)) => (number, Some(suffix.to_lowercase())),
+        None => (literal, None),
+    };
+    let Some((_, _, exponent)) = regex_captures!(r"^(\d*\.\d*|\d*\.?\d*([eEdD])[+-]?\d+)$", number)
+    else {
+        return NumericType::Integer;
+    };
+    let kind = match suffix {
+        Some(suffix) => RealKind::Named(suffix),
+        None if exponent.eq_ignore_ascii_case("d") => RealKind::Double,
+        None => RealKind::Default,
+    };
+    NumericType::Real(kind)
+}
+
+/// Get the type declared by a `variable_declaration`, if it's numeric
+pub fn declared_type(declaration: &Node, src: &str) -> Option<NumericType> {
+    let dtype = declaration.child_by_field_name("type")?;
+    if dtype.kind() != "intrinsic_type" {
+        return None;
+    }
+    let (name, kind) = match dtype.child_by_field_name("kind") {
+        Some(kind) => (
+            src.get(dtype.start_byte()..kind.start_byte())?,
+            RealKind::Named(kind_value(&kind, src)?.to_text(src)?.to_lowercase()),
+        ),
+        None => (dtype.to_text(src)?, RealKind::Default),
+    };
+    // Normalise `double precision` and `doubleprecision`
+    let name: String = name.split_whitespace().collect();
+    match name.to_lowercase().as_str() {
+        "integer" => Some(NumericType::Integer),
+        "real" => Some(NumericType::Real(kind)),
+        "complex" => Some(NumericType::Complex(kind)),
+        "doubleprecision" => Some(NumericType::Real(RealKind::Double)),
+        "doublecomplex" => Some(NumericType::Complex(RealKind::Double)),
+        _ => None,
+    }
+}
+
+/// Get the type returned by a call to an intrinsic conversion function
+fn conversion_type(call: &Node, name: &str, src: &str) -> Option<NumericType> {
+    // The kind is either the second argument, or a keyword argument
+    let kind = call.child_with_name("argument_list").and_then(|arguments| {
+        arguments
+            .named_children(&mut arguments.walk())
+            .enumerate()
+            .find_map(|(position, argument)| match argument.kind() {
+                "keyword_argument" if is_keyword_argument(&argument, "kind", src) => {
+                    argument.child_by_field_name("value")
+                }
+                "keyword_argument" => None,
+                _ if position == 1 && name != "cmplx" => Some(argument),
+                _ => None,
+            })
+            .and_then(|kind| kind.to_text(src))
+            .map(|kind| RealKind::Named(kind.to_lowercase()))
+    });
+    match name {
+        "int" | "nint" | "floor" | "ceiling" => Some(NumericType::Integer),
+        "real" | "float" | "sngl" => Some(NumericType::Real(kind.unwrap_or(RealKind::Default))),
+        "dble" | "dfloat" => Some(NumericType::Real(RealKind::Double)),
+        "cmplx" => Some(NumericType::Complex(kind.unwrap_or(RealKind::Default))),
+        _ => None,
+    }
+}
+
+/// Get the type of a numeric expression, as far as it can be worked out from
+/// literals, declarations in the enclosing scopes, and intrinsic conversion
+/// functions.
+pub fn numeric_type(node: &Node, src: &str) -> Option<NumericType> {
+    let node = strip_unary(node);
+    match node.kind() {
+        "number_literal" => Some(literal_type(node.to_text(src)?)),
+        "complex_literal" => {
+            let real = node.named_child(0)?;
+            match numeric_type(&real, src)? {
+                NumericType::Real(kind) => Some(NumericType::Complex(kind)),
+                _ => Some(NumericType::Complex(RealKind::Default)),
+            }
+        }
+        "identifier" => {
+            let declaration = variable_declaration(&node, node.to_text(src)?, src)?;
+            declared_type(&declaration, src)
+        }
+        "call_expression" => {
+            // Either an array element or a function call
+            let function = node.named_child(0)?;
+            if function.kind() != "identifier" {
+                return None;
+            }
+            let name = function.to_text(src)?;
+            match variable_declaration(&node, name, src) {
+                Some(declaration) => declared_type(&declaration, src),
+                None => conversion_type(&node, &name.to_lowercase(), src),
+            }
+        }
+        "math_expression" => {
+            let left = numeric_type(&node.child_by_field_name("left")?, src)?;
+            let right = numeric_type(&node.child_by_field_name("right")?, src)?;
+            let operator = node.child(1)?;
+            match (left, right) {
+                // Raising to an integer power doesn't change the type
+                (left, NumericType::Integer) if operator.kind() == "**" => Some(left),
+                (NumericType::Integer, NumericType::Integer) => Some(NumericType::Integer),
+                (NumericType::Complex(kind), _) | (_, NumericType::Complex(kind)) => {
+                    Some(NumericType::Complex(kind))
+                }
+                (NumericType::Real(kind), _) | (_, NumericType::Real(kind)) => {
+                    Some(NumericType::Real(kind))
+                }
+            }
+        }
+        _ => None,
+    }
+}
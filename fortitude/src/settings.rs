@@ -14,11 +14,18 @@ use ruff_macros::CacheKey;
 use serde::{de, Deserialize, Deserializer, Serialize};
 use strum::IntoEnumIterator;
 
+use crate::custom_rules::CustomRule;
 use crate::display_settings;
+use crate::fix_safety_table::FixSafetyTable;
 use crate::fs::{FilePatternSet, EXCLUDE_BUILTINS, FORTRAN_EXTS};
 use crate::registry::Rule;
 use crate::rule_selector::{CompiledPerFileIgnoreList, PreviewOptions, RuleSelector};
 use crate::rule_table::RuleTable;
+use crate::rules::correctness::settings::{
+    AllocateStatSettings, FloatingPointEqualitySettings, PrecisionSettings,
+};
+use crate::rules::modernisation::settings::DoublePrecisionSettings;
+use crate::rules::portability::settings::UnapprovedRealKindSettings;
 
 #[derive(Debug)]
 pub struct Settings {
@@ -55,6 +62,7 @@ pub struct CheckSettings {
     pub project_root: PathBuf,
 
     pub rules: RuleTable,
+    pub fix_safety: FixSafetyTable,
     pub per_file_ignores: CompiledPerFileIgnoreList,
 
     pub line_length: usize,
@@ -64,9 +72,16 @@ pub struct CheckSettings {
     pub show_fixes: bool,
     pub unsafe_fixes: UnsafeFixes,
     pub output_format: OutputFormat,
+    pub markdown_max_length: Option<usize>,
     pub progress_bar: ProgressBar,
     pub preview: PreviewMode,
     pub ignore_allow_comments: IgnoreAllowComments,
+    pub custom_rules: Vec<CustomRule>,
+    pub double_precision: DoublePrecisionSettings,
+    pub unapproved_real_kind: UnapprovedRealKindSettings,
+    pub floating_point_equality: FloatingPointEqualitySettings,
+    pub precision: PrecisionSettings,
+    pub allocate_stat: AllocateStatSettings,
 }
 
 impl CheckSettings {
@@ -77,6 +92,7 @@ impl CheckSettings {
                 .iter()
                 .flat_map(|selector| selector.rules(&PreviewOptions::default()))
                 .collect(),
+            fix_safety: FixSafetyTable::default(),
             per_file_ignores: CompiledPerFileIgnoreList::default(),
             line_length: 100,
             fix: false,
@@ -84,9 +100,16 @@ impl CheckSettings {
             show_fixes: false,
             unsafe_fixes: UnsafeFixes::default(),
             output_format: OutputFormat::default(),
+            markdown_max_length: None,
             progress_bar: ProgressBar::default(),
             preview: PreviewMode::default(),
             ignore_allow_comments: IgnoreAllowComments::default(),
+            custom_rules: Vec::new(),
+            double_precision: DoublePrecisionSettings::default(),
+            unapproved_real_kind: UnapprovedRealKindSettings::default(),
+            floating_point_equality: FloatingPointEqualitySettings::default(),
+            precision: PrecisionSettings::default(),
+            allocate_stat: AllocateStatSettings::default(),
         }
     }
 }
@@ -100,14 +123,22 @@ impl fmt::Display for CheckSettings {
             fields = [
                 self.project_root | path,
                 self.rules | nested,
+                self.fix_safety | nested,
                 self.per_file_ignores,
                 self.line_length,
                 self.fix,
                 self.fix_only,
                 self.show_fixes,
                 self.output_format,
+                self.markdown_max_length | optional,
                 self.progress_bar,
                 self.preview,
+                self.custom_rules | array,
+                self.double_precision | nested,
+                self.unapproved_real_kind | nested,
+                self.floating_point_equality | nested,
+                self.precision | nested,
+                self.allocate_stat | nested,
             ]
         }
         Ok(())
@@ -390,6 +421,10 @@ pub enum OutputFormat {
     Rdjson,
     Azure,
     Sarif,
+    Html,
+    Checkstyle,
+    Sonar,
+    Markdown,
 }
 
 impl fmt::Display for OutputFormat {
@@ -407,6 +442,10 @@ impl fmt::Display for OutputFormat {
             Self::Rdjson => write!(f, "rdjson"),
             Self::Azure => write!(f, "azure"),
             Self::Sarif => write!(f, "sarif"),
+            Self::Html => write!(f, "html"),
+            Self::Checkstyle => write!(f, "checkstyle"),
+            Self::Sonar => write!(f, "sonar"),
+            Self::Markdown => write!(f, "markdown"),
         }
     }
 }
@@ -1,15 +1,33 @@
 use std::io::Write;
+use std::path::Path;
 
 use anyhow::Result;
 
+use crate::cli::ConfigOverride;
 use crate::settings::Settings;
 
-pub(crate) fn show_settings(settings: &Settings, writer: &mut impl Write) -> Result<()> {
+pub(crate) fn show_settings(
+    settings: &Settings,
+    config_file: Option<&Path>,
+    config_overrides: &[ConfigOverride],
+    writer: &mut impl Write,
+) -> Result<()> {
     writeln!(
         writer,
         "Resolved settings for \"{}\"",
         settings.check.project_root.display()
     )?;
+    match config_file {
+        Some(config_file) => writeln!(writer, "Settings path: \"{}\"", config_file.display())?,
+        None => writeln!(writer, "Settings path: none")?,
+    }
+    if !config_overrides.is_empty() {
+        writeln!(writer, "Config overrides:")?;
+        for config_override in config_overrides {
+            writeln!(writer, "\t{config_override}")?;
+        }
+    }
+    writeln!(writer)?;
     write!(writer, "{settings}")?;
     Ok(())
 }
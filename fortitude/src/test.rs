@@ -49,7 +49,7 @@ pub(crate) fn test_contents(
     let rule_table = RuleTable::from_iter(rules.iter().cloned());
     let path_rules = rules_to_path_rules(&rule_table);
     let text_rules = rules_to_text_rules(&rule_table);
-    let ast_entrypoints = ast_entrypoint_map(&rule_table);
+    let ast_entrypoints = ast_entrypoint_map(&rule_table).unwrap();
 
     match check_file(
         &rule_table,
@@ -0,0 +1,167 @@
+//! Optional profiling of where time is spent while checking files, enabled
+//! with `--timing`.
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use crate::cli::HelpFormat;
+use crate::rules::Rule;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static TOTALS: LazyLock<Mutex<FxHashMap<Stage, Timing>>> = LazyLock::new(Default::default);
+
+/// Start collecting timings.
+pub(crate) fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Returns `true` if timings are being collected.
+pub(crate) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Something that we measure the time spent on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum Stage {
+    /// Parsing source code with tree-sitter.
+    Parse,
+    /// Running a single rule.
+    Rule(Rule),
+    /// Applying fixes and preparing the source for the next iteration.
+    Fix,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+struct Timing {
+    total: Duration,
+    calls: usize,
+}
+
+/// Accumulates timings locally, and adds them to the global totals when
+/// dropped. This means threads only need to take the lock once, rather than
+/// for every measurement.
+pub(crate) struct Timer {
+    timings: Option<FxHashMap<Stage, Timing>>,
+}
+
+impl Timer {
+    pub(crate) fn new() -> Self {
+        Self {
+            timings: is_enabled().then(FxHashMap::default),
+        }
+    }
+
+    /// Run `f`, adding the time it takes to `stage` if timing is enabled.
+    pub(crate) fn time<T>(&mut self, stage: Stage, f: impl FnOnce() -> T) -> T {
+        let Some(timings) = &mut self.timings else {
+            return f();
+        };
+        let start = Instant::now();
+        let result = f();
+        let timing = timings.entry(stage).or_default();
+        timing.total += start.elapsed();
+        timing.calls += 1;
+        result
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let Some(timings) = self.timings.take() else {
+            return;
+        };
+        let mut totals = TOTALS.lock().unwrap_or_else(|err| err.into_inner());
+        for (stage, timing) in timings {
+            let total = totals.entry(stage).or_default();
+            total.total += timing.total;
+            total.calls += timing.calls;
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct TimingRow {
+    stage: &'static str,
+    code: Option<String>,
+    name: Option<&'static str>,
+    total_ms: f64,
+    calls: usize,
+}
+
+/// Write the collected timings, most expensive first. Times are summed across
+/// all threads, so may add up to more than the elapsed wall time.
+pub(crate) fn write_timings(
+    writer: &mut dyn Write,
+    format: HelpFormat,
+    wall_time: Duration,
+) -> Result<()> {
+    let totals = TOTALS.lock().unwrap_or_else(|err| err.into_inner());
+    let rows = totals
+        .iter()
+        .sorted_by(|(a_stage, a), (b_stage, b)| b.total.cmp(&a.total).then(a_stage.cmp(b_stage)))
+        .map(|(stage, timing)| {
+            let (stage, code, name) = match stage {
+                Stage::Parse => ("parse", None, None),
+                Stage::Rule(rule) => (
+                    "rule",
+                    Some(rule.noqa_code().to_string()),
+                    Some(rule.as_ref()),
+                ),
+                Stage::Fix => ("fix", None, None),
+            };
+            TimingRow {
+                stage,
+                code,
+                name,
+                total_ms: timing.total.as_secs_f64() * 1000.0,
+                calls: timing.calls,
+            }
+        })
+        .collect_vec();
+
+    match format {
+        HelpFormat::Text => {
+            let name_width = rows
+                .iter()
+                .map(|row| row.name.map_or(row.stage.len(), str::len))
+                .chain(std::iter::once("Name".len()))
+                .max()
+                .unwrap_or_default();
+            writeln!(
+                writer,
+                "{:<8}{:<name_width$}  {:>12}  {:>10}",
+                "Code", "Name", "Time (ms)", "Calls"
+            )?;
+            for row in &rows {
+                writeln!(
+                    writer,
+                    "{:<8}{:<name_width$}  {:>12.3}  {:>10}",
+                    row.code.as_deref().unwrap_or(""),
+                    row.name.unwrap_or(row.stage),
+                    row.total_ms,
+                    row.calls,
+                )?;
+            }
+            writeln!(
+                writer,
+                "Total elapsed time: {:.3} ms",
+                wall_time.as_secs_f64() * 1000.0
+            )?;
+        }
+        HelpFormat::Json => {
+            let report = serde_json::json!({
+                "elapsed_ms": wall_time.as_secs_f64() * 1000.0,
+                "timings": rows,
+            });
+            writeln!(writer, "{}", serde_json::to_string_pretty(&report)?)?;
+        }
+    }
+    Ok(())
+}
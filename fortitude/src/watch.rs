@@ -0,0 +1,393 @@
+//! Continuously re-check files as they change, for `fortitude check --watch`.
+use std::collections::BTreeMap;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use log::debug;
+use notify::{recommended_watcher, Event, EventKind, RecursiveMode, Watcher};
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
+use crate::check::{
+    ast_entrypoint_map, check_files, fix_mode_for, is_stdin, make_printer, resolve_settings,
+    rules_to_path_rules, rules_to_text_rules, AstEntrypoints, CheckResults,
+};
+use crate::cli::{CheckArgs, GlobalConfigArgs};
+use crate::fs::{self, get_files};
+use crate::rules::{PathRuleEnum, TextRuleEnum};
+use crate::settings::{FixMode, ProgressBar, Settings};
+
+/// How long to wait for further changes before checking again. Editors often
+/// save a file in several steps, and we only want to check it once.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Everything needed to check the project, which is rebuilt whenever the
+/// config file changes.
+struct Session {
+    settings: Settings,
+    /// The config file in use, as an absolute path.
+    config_file: Option<PathBuf>,
+    path_rules: Vec<PathRuleEnum>,
+    text_rules: Vec<TextRuleEnum>,
+    ast_entrypoints: AstEntrypoints,
+    fix_mode: FixMode,
+    /// The latest results for each file, keyed by absolute path.
+    results: BTreeMap<PathBuf, CheckResults>,
+}
+
+impl Session {
+    fn new(args: &CheckArgs, global_options: &GlobalConfigArgs) -> Result<Self> {
+        let (mut settings, config_file) = resolve_settings(args, global_options)?;
+        // The screen gets cleared between checks, so a progress bar isn't useful
+        settings.check.progress_bar = ProgressBar::Off;
+
+        let rules = &settings.check.rules;
+        Ok(Self {
+            path_rules: rules_to_path_rules(rules),
+            text_rules: rules_to_text_rules(rules),
+            ast_entrypoints: ast_entrypoint_map(rules)?,
+            fix_mode: fix_mode_for(&settings.check),
+            config_file: config_file.map(fs::normalize_path),
+            settings,
+            results: BTreeMap::new(),
+        })
+    }
+
+    /// The files selected by the current settings, as absolute paths.
+    fn files(&self) -> Result<FxHashSet<PathBuf>> {
+        Ok(get_files(&self.settings.file_resolver, false)?
+            .into_iter()
+            .map(fs::normalize_path)
+            .collect())
+    }
+
+    /// Check each of `files`, replacing any previous results for them.
+    fn check(&mut self, files: &[PathBuf]) -> Result<()> {
+        let results = files
+            .par_iter()
+            .map(|file| {
+                let results = check_files(
+                    std::slice::from_ref(file),
+                    &self.settings.check.rules,
+                    &self.path_rules,
+                    &self.text_rules,
+                    &self.ast_entrypoints,
+                    &self.settings,
+                    self.fix_mode,
+                    self.settings.check.ignore_allow_comments,
+                )?;
+                Ok((file.clone(), results))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.results.extend(results);
+        Ok(())
+    }
+
+    /// Clear the screen and print the combined results for all files.
+    fn report(&self, writer: &mut dyn Write, global_options: &GlobalConfigArgs) -> Result<()> {
+        let mut results = self
+            .results
+            .values()
+            .cloned()
+            .fold(CheckResults::new(), CheckResults::merge);
+        results.sort();
+
+        // Clear the screen and move the cursor to the top left
+        write!(writer, "\x1B[2J\x1B[1;1H")?;
+        make_printer(
+            &self.settings.check,
+            self.fix_mode,
+            global_options.log_level(),
+        )
+        .write_once(&results, writer)?;
+        writeln!(
+            writer,
+            "[{}] {}",
+            chrono::Local::now().format("%H:%M:%S"),
+            "Watching for file changes.".bold()
+        )?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Start watching the directories containing each of `files` and the
+    /// config file. Watching directories rather than the files themselves
+    /// means we notice new files, and files that editors replace on saving.
+    fn watch_directories(
+        &self,
+        watcher: &mut impl Watcher,
+        watched: &mut FxHashSet<PathBuf>,
+        files: &FxHashSet<PathBuf>,
+    ) -> Result<()> {
+        let directories = files
+            .iter()
+            .chain(self.config_file.as_ref())
+            .filter_map(|path| path.parent())
+            .map(Path::to_path_buf)
+            .collect::<FxHashSet<_>>();
+
+        for directory in directories {
+            if !watched.contains(&directory) {
+                watcher
+                    .watch(&directory, RecursiveMode::NonRecursive)
+                    .with_context(|| format!("Failed to watch {}", directory.display()))?;
+                watched.insert(directory);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check all files, then check them again whenever they change, until
+/// interrupted.
+pub(crate) fn watch(args: &CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitCode> {
+    if is_stdin(
+        args.files.as_deref().unwrap_or_default(),
+        args.stdin_filename.as_deref(),
+    ) {
+        bail!("Cannot watch standard input");
+    }
+
+    let mut writer = BufWriter::new(io::stdout());
+
+    let (sender, receiver) = channel();
+    let mut watcher = recommended_watcher(sender)?;
+    let mut watched = FxHashSet::default();
+
+    let mut session = Session::new(args, global_options)?;
+    let mut files = session.files()?;
+    session.check(&files.iter().cloned().collect::<Vec<_>>())?;
+    session.watch_directories(&mut watcher, &mut watched, &files)?;
+    session.report(&mut writer, global_options)?;
+
+    loop {
+        let changed = wait_for_changes(&receiver)?;
+        debug!("Changed paths: {changed:?}");
+
+        if config_changed(session.config_file.as_deref(), &changed) {
+            // Keep using the old settings until the config file is valid again
+            session = match Session::new(args, global_options) {
+                Ok(session) => session,
+                Err(err) => {
+                    writeln!(
+                        writer,
+                        "{} {err:#}",
+                        "Failed to reload configuration:".red().bold()
+                    )?;
+                    writer.flush()?;
+                    continue;
+                }
+            };
+            files = session.files()?;
+            session.check(&files.iter().cloned().collect::<Vec<_>>())?;
+        } else {
+            // Files may have been created or deleted, or renamed so that they
+            // are now excluded
+            let new_files = session.files()?;
+            let Some(to_check) = files_to_recheck(&changed, &files, &new_files) else {
+                continue;
+            };
+
+            session.results.retain(|file, _| new_files.contains(file));
+            session.check(&to_check)?;
+            files = new_files;
+        }
+
+        session.watch_directories(&mut watcher, &mut watched, &files)?;
+        session.report(&mut writer, global_options)?;
+    }
+}
+
+/// Whether the config file is among the `changed` paths, in which case the
+/// settings must be reloaded and every file checked again.
+fn config_changed(config_file: Option<&Path>, changed: &FxHashSet<PathBuf>) -> bool {
+    config_file.is_some_and(|config_file| changed.contains(config_file))
+}
+
+/// The files to check again after `changed` paths changed on disk, given the
+/// files selected before (`old_files`) and after (`new_files`) the change.
+/// These are the changed files that are still selected, plus any newly
+/// selected files. Returns `None` if there is nothing to check and no files
+/// were removed, so there is nothing new to report.
+fn files_to_recheck(
+    changed: &FxHashSet<PathBuf>,
+    old_files: &FxHashSet<PathBuf>,
+    new_files: &FxHashSet<PathBuf>,
+) -> Option<Vec<PathBuf>> {
+    let mut to_check = new_files
+        .iter()
+        .filter(|file| changed.contains(*file) || !old_files.contains(*file))
+        .cloned()
+        .collect::<Vec<_>>();
+    to_check.sort();
+    let removed = old_files.difference(new_files).count();
+    if to_check.is_empty() && removed == 0 {
+        return None;
+    }
+    Some(to_check)
+}
+
+/// Block until something changes on disk, then wait for any further changes
+/// that arrive in quick succession. Returns all paths that changed.
+fn wait_for_changes(receiver: &Receiver<notify::Result<Event>>) -> Result<FxHashSet<PathBuf>> {
+    let mut changed = FxHashSet::default();
+
+    let mut event = receiver.recv().context("File watcher stopped")?;
+    loop {
+        let current = event?;
+        // Ignore events from just reading files, including our own reads
+        if matches!(
+            current.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            changed.extend(current.paths.into_iter().map(fs::normalize_path));
+        }
+
+        event = match receiver.recv_timeout(DEBOUNCE) {
+            Ok(event) => event,
+            Err(RecvTimeoutError::Timeout) if changed.is_empty() => {
+                receiver.recv().context("File watcher stopped")?
+            }
+            Err(RecvTimeoutError::Timeout) => return Ok(changed),
+            Err(RecvTimeoutError::Disconnected) => bail!("File watcher stopped"),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::channel;
+    use std::thread;
+
+    use notify::event::{AccessKind, CreateKind, ModifyKind, RemoveKind};
+    use notify::{Event, EventKind};
+    use rustc_hash::FxHashSet;
+
+    use super::{config_changed, files_to_recheck, wait_for_changes, DEBOUNCE};
+
+    fn paths(paths: &[&str]) -> FxHashSet<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    fn event(kind: EventKind, path: &str) -> notify::Result<Event> {
+        Ok(Event::new(kind).add_path(PathBuf::from(path)))
+    }
+
+    #[test]
+    fn changes_in_quick_succession_are_combined() -> anyhow::Result<()> {
+        let (sender, receiver) = channel();
+        let handle = thread::spawn(move || {
+            sender
+                .send(event(EventKind::Create(CreateKind::File), "/project/a.f90"))
+                .unwrap();
+            thread::sleep(DEBOUNCE / 4);
+            sender
+                .send(event(EventKind::Modify(ModifyKind::Any), "/project/b.f90"))
+                .unwrap();
+            thread::sleep(DEBOUNCE / 4);
+            sender
+                .send(event(EventKind::Remove(RemoveKind::File), "/project/c.f90"))
+                .unwrap();
+            // Keep the channel open until well after the debounce has expired
+            thread::sleep(DEBOUNCE * 4);
+            sender
+                .send(event(EventKind::Modify(ModifyKind::Any), "/project/d.f90"))
+                .unwrap();
+            thread::sleep(DEBOUNCE * 4);
+        });
+
+        let changed = wait_for_changes(&receiver)?;
+        assert_eq!(
+            changed,
+            paths(&["/project/a.f90", "/project/b.f90", "/project/c.f90"])
+        );
+
+        let changed = wait_for_changes(&receiver)?;
+        assert_eq!(changed, paths(&["/project/d.f90"]));
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn access_events_are_ignored() -> anyhow::Result<()> {
+        let (sender, receiver) = channel();
+        let handle = thread::spawn(move || {
+            sender
+                .send(event(EventKind::Access(AccessKind::Any), "/project/a.f90"))
+                .unwrap();
+            // Reading a file shouldn't end the wait, even once the debounce
+            // has expired
+            thread::sleep(DEBOUNCE * 2);
+            sender
+                .send(event(EventKind::Modify(ModifyKind::Any), "/project/b.f90"))
+                .unwrap();
+            thread::sleep(DEBOUNCE * 4);
+        });
+
+        let changed = wait_for_changes(&receiver)?;
+        assert_eq!(changed, paths(&["/project/b.f90"]));
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn stopped_watcher_is_an_error() {
+        let (sender, receiver) = channel::<notify::Result<Event>>();
+        drop(sender);
+        assert!(wait_for_changes(&receiver).is_err());
+    }
+
+    #[test]
+    fn config_change_triggers_full_recheck() {
+        let config_file = Path::new("/project/fortitude.toml");
+        assert!(config_changed(
+            Some(config_file),
+            &paths(&["/project/a.f90", "/project/fortitude.toml"])
+        ));
+        assert!(!config_changed(
+            Some(config_file),
+            &paths(&["/project/a.f90"])
+        ));
+        assert!(!config_changed(None, &paths(&["/project/fortitude.toml"])));
+    }
+
+    #[test]
+    fn changed_and_new_files_are_rechecked() {
+        let old_files = paths(&["/project/a.f90", "/project/b.f90"]);
+        let new_files = paths(&["/project/a.f90", "/project/b.f90", "/project/c.f90"]);
+        let changed = paths(&["/project/b.f90", "/project/c.f90"]);
+        assert_eq!(
+            files_to_recheck(&changed, &old_files, &new_files),
+            Some(vec![
+                PathBuf::from("/project/b.f90"),
+                PathBuf::from("/project/c.f90")
+            ])
+        );
+    }
+
+    #[test]
+    fn excluded_paths_are_ignored() {
+        let files = paths(&["/project/a.f90"]);
+        let changed = paths(&["/project/build/generated.f90", "/project/notes.txt"]);
+        assert_eq!(files_to_recheck(&changed, &files, &files), None);
+    }
+
+    #[test]
+    fn removed_files_are_reported() {
+        let old_files = paths(&["/project/a.f90", "/project/b.f90"]);
+        let new_files = paths(&["/project/a.f90"]);
+        let changed = paths(&["/project/b.f90"]);
+        assert_eq!(
+            files_to_recheck(&changed, &old_files, &new_files),
+            Some(vec![])
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::fs;
+use std::path::Path;
+
+use fortitude::registry::Rule;
+use fortitude::{fix_source, lint_source, RuleSelector, SettingsBuilder};
+use tempfile::TempDir;
+
+const SOURCE: &str = "
+program test
+  logical*4, parameter :: true = .true.
+  integer :: i  
+end program test
+";
+
+fn selectors(codes: &[&str]) -> Vec<RuleSelector> {
+    codes.iter().map(|code| code.parse().unwrap()).collect()
+}
+
+#[test]
+fn lint_source_default_settings() -> anyhow::Result<()> {
+    let settings = SettingsBuilder::new().build()?;
+    let messages = lint_source(SOURCE, Path::new("test.f90"), &settings)?;
+
+    let rules: Vec<_> = messages
+        .iter()
+        .filter_map(|message| message.rule())
+        .collect();
+    for rule in [
+        Rule::ImplicitTyping,
+        Rule::LiteralKind,
+        Rule::StarKind,
+        Rule::TrailingWhitespace,
+    ] {
+        assert!(rules.contains(&rule), "{rule:?} not in {rules:?}");
+    }
+
+    let star_kind = messages
+        .iter()
+        .find(|message| message.rule() == Some(Rule::StarKind))
+        .unwrap();
+    let location = star_kind.compute_start_location();
+    assert_eq!(location.row.get(), 3);
+    assert_eq!(location.column.get(), 10);
+    assert_eq!(star_kind.filename(), "test.f90");
+    Ok(())
+}
+
+#[test]
+fn lint_source_select_and_ignore() -> anyhow::Result<()> {
+    let settings = SettingsBuilder::new()
+        .select(selectors(&["C001", "PORT021", "S101"]))
+        .ignore(selectors(&["trailing-whitespace"]))
+        .build()?;
+    let messages = lint_source(SOURCE, Path::new("test.f90"), &settings)?;
+
+    let rules: Vec<_> = messages
+        .iter()
+        .filter_map(|message| message.rule())
+        .collect();
+    assert_eq!(rules, [Rule::ImplicitTyping, Rule::StarKind]);
+    Ok(())
+}
+
+#[test]
+fn lint_source_per_file_ignores() -> anyhow::Result<()> {
+    let settings = SettingsBuilder::new()
+        .select(selectors(&["PORT021"]))
+        .per_file_ignores("legacy_*.f90", selectors(&["PORT021"]))
+        .build()?;
+
+    let ignored = lint_source(SOURCE, Path::new("legacy_code.f90"), &settings)?;
+    assert!(ignored.is_empty());
+
+    let checked = lint_source(SOURCE, Path::new("modern_code.f90"), &settings)?;
+    assert_eq!(checked.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn fix_source_safe_fixes() -> anyhow::Result<()> {
+    let settings = SettingsBuilder::new()
+        .select(selectors(&["PORT021", "S101"]))
+        .build()?;
+    let fixed = fix_source(SOURCE, Path::new("test.f90"), &settings)?;
+
+    // Only the trailing whitespace fix is safe
+    let expected = r#"
+program test
+  logical*4, parameter :: true = .true.
+  integer :: i
+end program test
+"#;
+    assert_eq!(fixed.source, expected);
+    assert_eq!(fixed.fixed.get(&Rule::TrailingWhitespace), Some(&1));
+
+    let remaining: Vec<_> = fixed.messages.iter().filter_map(|m| m.rule()).collect();
+    assert_eq!(remaining, [Rule::StarKind]);
+    Ok(())
+}
+
+#[test]
+fn fix_source_safe_kind_fixes() -> anyhow::Result<()> {
+    let source = "
+program test
+  real*8 :: x
+end program test
+";
+
+    let settings = SettingsBuilder::new()
+        .select(selectors(&["PORT021"]))
+        .build()?;
+    let fixed = fix_source(source, Path::new("test.f90"), &settings)?;
+
+    let expected = r#"
+program test
+  use, intrinsic :: iso_fortran_env, only: real64
+  real(real64) :: x
+end program test
+"#;
+    assert_eq!(fixed.source, expected);
+    assert_eq!(fixed.fixed.get(&Rule::StarKind), Some(&1));
+    assert!(fixed.messages.is_empty());
+    Ok(())
+}
+
+#[test]
+fn fix_source_unsafe_fixes() -> anyhow::Result<()> {
+    // There's no `iso_fortran_env` parameter for 10 byte reals, and `logical`
+    // kinds needn't match `integer` kinds, so these fixes are unsafe
+    let source = "
+program test
+  real*10 :: x
+  logical*4 :: y
+end program test
+";
+
+    let settings = SettingsBuilder::new()
+        .select(selectors(&["PORT021"]))
+        .build()?;
+    let fixed = fix_source(source, Path::new("test.f90"), &settings)?;
+    assert_eq!(fixed.source, source);
+    let remaining: Vec<_> = fixed.messages.iter().filter_map(|m| m.rule()).collect();
+    assert_eq!(remaining, [Rule::StarKind, Rule::StarKind]);
+
+    let settings = SettingsBuilder::new()
+        .select(selectors(&["PORT021"]))
+        .unsafe_fixes(true)
+        .build()?;
+    let fixed = fix_source(source, Path::new("test.f90"), &settings)?;
+
+    let expected = r#"
+program test
+  use, intrinsic :: iso_fortran_env, only: int32
+  real(10) :: x
+  logical(int32) :: y
+end program test
+"#;
+    assert_eq!(fixed.source, expected);
+    assert!(fixed.messages.is_empty());
+    Ok(())
+}
+
+#[test]
+fn fix_source_syntax_error() -> anyhow::Result<()> {
+    let source = "
+program test
+  logical*4 :: x  
+  x = (
+end program test
+";
+
+    let settings = SettingsBuilder::new()
+        .select(selectors(&["E001", "PORT021", "S101"]))
+        .build()?;
+    let fixed = fix_source(source, Path::new("test.f90"), &settings)?;
+    assert_eq!(fixed.source, source);
+    assert!(fixed.fixed.is_empty());
+    let remaining: Vec<_> = fixed.messages.iter().filter_map(|m| m.rule()).collect();
+    assert!(
+        remaining.contains(&Rule::SyntaxError),
+        "SyntaxError not in {remaining:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn settings_from_config_file() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let config_file = tempdir.path().join("fortitude.toml");
+    fs::write(
+        &config_file,
+        r#"
+[check]
+select = ["S001"]
+line-length = 20
+"#,
+    )?;
+
+    let settings = SettingsBuilder::from_config_file(&config_file)?.build()?;
+    let messages = lint_source(SOURCE, Path::new("test.f90"), &settings)?;
+
+    let rules: Vec<_> = messages
+        .iter()
+        .filter_map(|message| message.rule())
+        .collect();
+    assert_eq!(rules, [Rule::LineTooLong]);
+    Ok(())
+}
+
+#[test]
+fn invalid_config_file() {
+    assert!(SettingsBuilder::from_config_file("does/not/exist/fortitude.toml").is_err());
+}
+
+#[test]
+fn lint_paths_with_config_file() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let config_file = tempdir.path().join("fortitude.toml");
+    fs::write(
+        &config_file,
+        r#"
+[check]
+select = ["PORT021", "S101"]
+"#,
+    )?;
+    fs::write(tempdir.path().join("test.f90"), SOURCE)?;
+    fs::write(tempdir.path().join("notes.txt"), SOURCE)?;
+
+    let settings = fortitude::resolve_settings(&[tempdir.path()], Some(&config_file))?;
+    let messages = fortitude::lint_paths(&settings)?;
+
+    let rules: Vec<_> = messages
+        .iter()
+        .filter_map(|message| message.rule())
+        .collect();
+    assert_eq!(rules, [Rule::StarKind, Rule::TrailingWhitespace]);
+    assert!(messages
+        .iter()
+        .all(|message| message.filename().ends_with("test.f90")));
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use assert_cmd::prelude::*;
 use insta_cmd::assert_cmd_snapshot;
+use predicates::prelude::*;
 use std::path::{Path, PathBuf};
 use std::{fs, process::Command};
 use tempfile::TempDir;
@@ -145,7 +146,7 @@ unknown-key = 1
           |
         3 | unknown-key = 1
           | ^^^^^^^^^^^
-        unknown field `unknown-key`, expected one of `files`, `fix`, `unsafe-fixes`, `show-fixes`, `fix-only`, `output-format`, `preview`, `progress-bar`, `ignore`, `select`, `extend-select`, `file-extensions`, `exclude`, `extend-exclude`, `force-exclude`, `respect-gitignore`, `line-length`, `per-file-ignores`
+        unknown field `unknown-key`, expected one of `files`, `fix`, `unsafe-fixes`, `show-fixes`, `fix-only`, `output-format`, `markdown-max-length`, `preview`, `progress-bar`, `ignore`, `select`, `extend-select`, `preset`, `fixable`, `unfixable`, `extend-safe-fixes`, `extend-unsafe-fixes`, `file-extensions`, `exclude`, `extend-exclude`, `force-exclude`, `respect-gitignore`, `line-length`, `per-file-ignores`, `custom-rules`, `double-precision`, `unapproved-real-kind`, `floating-point-equality`, `precision`, `allocate-stat`
     ");
     Ok(())
 }
@@ -187,7 +188,7 @@ end program
       |          ^^ PORT021
     4 | end program
       |
-      = help: Replace with 'logical(4)'
+      = help: Replace with 'logical(int32)'
 
     [TEMP_FILE] PORT011 logical kind set with number literal '4'
       |
@@ -541,6 +542,152 @@ end program foo
     Ok(())
 }
 
+#[test]
+fn unfixable_rules_are_not_fixed() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program foo
+  implicit none
+  real i
+  i = 4.0
+contains
+  subroutine bar
+    implicit none
+  end subroutine bar
+end program foo
+"#,
+    )?;
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("--config")
+                         .arg(r#"check.unfixable = ["S201"]"#)
+                         .arg("check")
+                         .arg("--select=S071,C022,S201,C003")
+                         .arg("--preview")
+                         .arg("--fix")
+                         .arg(&test_file),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [TEMP_FILE] C003 'implicit none' missing 'external'
+      |
+    2 | program foo
+    3 |   implicit none
+      |   ^^^^^^^^^^^^^ C003
+    4 |   real :: i
+    5 |   i = 4.0
+      |
+      = help: Add `(external)` to 'implicit none'
+
+    [TEMP_FILE] C022 real has implicit kind
+      |
+    2 | program foo
+    3 |   implicit none
+    4 |   real :: i
+      |   ^^^^ C022
+    5 |   i = 4.0
+    6 | contains
+      |
+
+    [TEMP_FILE] S201 'implicit none' set on the enclosing program
+       |
+     6 | contains
+     7 |   subroutine bar
+     8 |     implicit none
+       |     ^^^^^^^^^^^^^ S201
+     9 |   end subroutine bar
+    10 | end program foo
+       |
+       = help: Remove unnecessary 'implicit none'
+
+    fortitude: 1 files scanned.
+    Number of errors: 4 (1 fixed, 3 remaining)
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+    No fixes available (1 hidden fix can be enabled with the `--unsafe-fixes` option).
+    1 fix withheld by the `fixable` and `unfixable` settings.
+
+    ----- stderr -----
+    ");
+
+    let expected = r#"
+program foo
+  implicit none
+  real :: i
+  i = 4.0
+contains
+  subroutine bar
+    implicit none
+  end subroutine bar
+end program foo
+"#
+    .to_string();
+
+    let transformed = fs::read_to_string(&test_file)?;
+    assert_eq!(transformed, expected);
+
+    Ok(())
+}
+
+#[test]
+fn extend_safe_fixes() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program foo
+  implicit none
+  real :: i
+  i = 4.0
+end program foo
+"#,
+    )?;
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("--config")
+                         .arg(r#"check.extend-safe-fixes = ["C003"]"#)
+                         .arg("check")
+                         .arg("--select=C003")
+                         .arg("--fix")
+                         .arg(&test_file),
+                         @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fortitude: 1 files scanned.
+    Number of errors: 1 (1 fixed, 0 remaining)
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+
+    ----- stderr -----
+    ");
+
+    let expected = r#"
+program foo
+  implicit none (type, external)
+  real :: i
+  i = 4.0
+end program foo
+"#
+    .to_string();
+
+    let transformed = fs::read_to_string(&test_file)?;
+    assert_eq!(transformed, expected);
+
+    Ok(())
+}
+
 #[test]
 fn apply_unsafe_fixes() -> anyhow::Result<()> {
     let tempdir = TempDir::new()?;
@@ -1463,7 +1610,7 @@ end program
       |          ^^ PORT021
     8 | end program
       |
-      = help: Replace with 'logical(4)'
+      = help: Replace with 'logical(int32)'
 
     fortitude: 1 files scanned.
     Number of errors: 1
@@ -1525,7 +1672,7 @@ end program
     6 |   ! allow(trailing-whitespace)
     7 |   logical*4, parameter :: false = .false.  
       |
-      = help: Replace with 'logical(4)'
+      = help: Replace with 'logical(int32)'
 
     [TEMP_FILE] PORT011 logical kind set with number literal '4'
       |
@@ -1546,7 +1693,7 @@ end program
       |          ^^ PORT021
     8 | end program
       |
-      = help: Replace with 'logical(4)'
+      = help: Replace with 'logical(int32)'
 
     [TEMP_FILE] PORT011 logical kind set with number literal '4'
       |
@@ -1712,6 +1859,128 @@ end program myprogram
     Ok(())
 }
 
+#[test]
+fn check_config_override() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let config_file = tempdir.path().join("fortitude.toml");
+    let fortran_file = tempdir.path().join("myfile.ff");
+    fs::write(
+        &config_file,
+        r#"
+[check]
+file-extensions = ["ff"]
+line-length = 10
+"#,
+    )?;
+    fs::write(
+        &fortran_file,
+        r#"
+program myprogram
+end program myprogram
+"#,
+    )?;
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("--config")
+                         .arg("check.line-length = 20")
+                         .arg("check")
+                         .arg("--select=S001")
+                         .current_dir(tempdir.path()),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    myfile.ff:3:21: S001 line length of 21, exceeds maximum 20
+      |
+    2 | program myprogram
+    3 | end program myprogram
+      |                     ^ S001
+      |
+
+    fortitude: 1 files scanned.
+    Number of errors: 1
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+
+    ----- stderr -----
+    ");
+    Ok(())
+}
+
+#[test]
+fn check_config_override_cli_precedence() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let fortran_file = tempdir.path().join("myfile.f90");
+    fs::write(
+        &fortran_file,
+        r#"
+program myprogram
+end program myprogram
+"#,
+    )?;
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("--config")
+                         .arg("check.line-length = 10")
+                         .arg("check")
+                         .arg("--select=S001")
+                         .arg("--line-length=30")
+                         .current_dir(tempdir.path()),
+                         @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fortitude: 1 files scanned.
+    All checks passed!
+
+
+    ----- stderr -----
+    ");
+    Ok(())
+}
+
+#[test]
+fn check_isolated() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let config_file = tempdir.path().join("fortitude.toml");
+    let fortran_file = tempdir.path().join("myfile.f90");
+    fs::write(
+        &config_file,
+        r#"
+[check]
+file-extensions = ["ff"]
+line-length = 10
+"#,
+    )?;
+    fs::write(
+        &fortran_file,
+        r#"
+program myprogram
+end program myprogram
+"#,
+    )?;
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("--isolated")
+                         .arg("check")
+                         .arg("--select=S001")
+                         .current_dir(tempdir.path()),
+                         @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fortitude: 1 files scanned.
+    All checks passed!
+
+
+    ----- stderr -----
+    ");
+    Ok(())
+}
+
 fn gitignore_test_path<P: AsRef<Path>>(tempdir: P) -> PathBuf {
     let base_path = tempdir.as_ref().join("base");
     let include_dir = base_path.join("include");
@@ -2026,3 +2295,337 @@ end program test
 
     Ok(())
 }
+
+#[test]
+fn timing() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  logical*4, parameter :: true = .true.
+end program test
+"#,
+    )?;
+    Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .arg(&test_file)
+        .args(["--select=PORT021", "--fix", "--unsafe-fixes", "--timing"])
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::is_match(r"(?m)^Code\s+Name\s+Time \(ms\)\s+Calls$")?
+                .and(predicate::str::is_match(
+                    r"(?m)^PORT021\s+star-kind\s+\d+\.\d{3}\s+\d+$",
+                )?)
+                .and(predicate::str::is_match(
+                    r"(?m)^\s+parse\s+\d+\.\d{3}\s+2$",
+                )?)
+                .and(predicate::str::is_match(r"(?m)^\s+fix\s+\d+\.\d{3}\s+2$")?)
+                .and(predicate::str::contains("Total elapsed time:")),
+        );
+
+    Ok(())
+}
+
+#[test]
+fn timing_before_path() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  logical, parameter :: true = .true.
+end program test
+"#,
+    )?;
+    Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .args(["--select=PORT021", "--timing"])
+        .arg(&test_file)
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::is_match(r"(?m)^PORT021\s+star-kind\s+\d+\.\d{3}\s+\d+$")?
+                .and(predicate::str::contains("Total elapsed time:")),
+        );
+
+    Ok(())
+}
+
+#[test]
+fn timing_json() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  logical*4, parameter :: true = .true.
+end program test
+"#,
+    )?;
+    Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .arg(&test_file)
+        .args(["--select=PORT021", "--timing=json"])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains(r#""elapsed_ms":"#)
+                .and(predicate::str::contains(r#""code": "PORT021""#))
+                .and(predicate::str::contains(r#""name": "star-kind""#))
+                .and(predicate::str::contains(r#""stage": "parse""#)),
+        );
+
+    Ok(())
+}
+
+#[test]
+fn fix_interactive() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  logical*4, parameter :: true = .true.
+  integer*8, parameter :: eight = 8
+end program test
+"#,
+    )?;
+    Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .arg(&test_file)
+        .args([
+            "--select=PORT021",
+            "--fix",
+            "--unsafe-fixes",
+            "--interactive",
+        ])
+        .write_stdin("a\ns\n")
+        .assert()
+        .failure()
+        .stdout(
+            predicate::str::contains("Apply this fix?").and(predicate::str::contains(
+                "Number of errors: 2 (1 fixed, 1 remaining)",
+            )),
+        );
+
+    let expected = r#"
+program test
+  use, intrinsic :: iso_fortran_env, only: int32
+  logical(int32), parameter :: true = .true.
+  integer*8, parameter :: eight = 8
+end program test
+"#;
+    assert_eq!(fs::read_to_string(&test_file)?, expected);
+
+    Ok(())
+}
+
+#[test]
+fn fix_interactive_from_config() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  integer*8, parameter :: eight = 8
+end program test
+"#,
+    )?;
+    let config_file = tempdir.path().join("fortitude.toml");
+    fs::write(
+        &config_file,
+        r#"
+[check]
+fix = true
+"#,
+    )?;
+    Command::cargo_bin(BIN_NAME)?
+        .args([
+            "--config-file",
+            config_file.as_os_str().to_string_lossy().as_ref(),
+        ])
+        .arg("check")
+        .arg(&test_file)
+        .args(["--select=PORT021", "--interactive"])
+        .write_stdin("a\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Apply this fix?"));
+
+    let expected = r#"
+program test
+  use, intrinsic :: iso_fortran_env, only: int64
+  integer(int64), parameter :: eight = 8
+end program test
+"#;
+    assert_eq!(fs::read_to_string(&test_file)?, expected);
+
+    Ok(())
+}
+
+#[test]
+fn fix_interactive_without_fix() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  integer*8, parameter :: eight = 8
+end program test
+"#,
+    )?;
+    Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .arg(&test_file)
+        .args(["--select=PORT021", "--interactive"])
+        .write_stdin("a\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Cannot review fixes interactively unless fixes are enabled",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn fix_interactive_overlapping() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  logical*4, parameter :: true = .true.
+  integer*8, parameter :: eight = 8
+end program test
+"#,
+    )?;
+    // Both fixes add a `use` statement in the same place, so only the first
+    // can be applied
+    Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .arg(&test_file)
+        .args([
+            "--select=PORT021",
+            "--fix",
+            "--unsafe-fixes",
+            "--interactive",
+        ])
+        .write_stdin("a\na\n")
+        .assert()
+        .failure()
+        .stdout(
+            predicate::str::contains(
+                "Skipped 1 accepted star-kind fix that overlapped other fixes.",
+            )
+            .and(predicate::str::contains(
+                "Number of errors: 2 (1 fixed, 1 remaining)",
+            )),
+        );
+
+    Ok(())
+}
+
+fn custom_rules_test_path<P: AsRef<Path>>(tempdir: P) -> anyhow::Result<PathBuf> {
+    let path = tempdir.as_ref().to_path_buf();
+    fs::write(
+        path.join("fortitude.toml"),
+        r#"
+[check]
+per-file-ignores = { "legacy.f90" = ["HOUSE001"] }
+
+[[check.custom-rules]]
+name = "no-mpi-abort"
+code = "HOUSE001"
+message = "Use `fatal_error` instead of `{function}`"
+query = "(subroutine_call (identifier) @function) @violation"
+predicates = { function = "^mpi_abort$" }
+"#,
+    )?;
+    let source = r#"
+program test
+  implicit none
+  integer :: ierr
+  call MPI_Abort(0, 1, ierr)
+  ! allow(no-mpi-abort)
+  call mpi_abort(0, 1, ierr)
+  call mpi_finalize(ierr)
+end program test
+"#;
+    fs::write(path.join("test.f90"), source)?;
+    fs::write(path.join("legacy.f90"), source)?;
+    Ok(path)
+}
+
+#[test]
+fn check_custom_rules() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let path = custom_rules_test_path(tempdir.path())?;
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .args(["--select=HOUSE001", "--output-format=concise"])
+                         .current_dir(&path),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.f90:5:3: HOUSE001 Use `fatal_error` instead of `MPI_Abort`
+    fortitude: 2 files scanned.
+    Number of errors: 1
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+
+    ----- stderr -----
+    ");
+
+    Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .args(["--select=HOUSE001", "--output-format=json"])
+        .current_dir(&path)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(r#""code": "HOUSE001""#));
+
+    // Custom rules are disabled when not selected
+    Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .args(["--select=S101"])
+        .current_dir(&path)
+        .assert()
+        .success();
+    Ok(())
+}
+
+#[test]
+fn check_unknown_custom_rule() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let path = custom_rules_test_path(tempdir.path())?;
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .args(["--select=HOUSE002"])
+                         .current_dir(&path),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    fortitude failed
+    Error: Unknown rule selector: `HOUSE002`
+    ");
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+const BIN_NAME: &str = "fortitude";
+
+#[test]
+fn config_list_all() -> anyhow::Result<()> {
+    Command::cargo_bin(BIN_NAME)?
+        .arg("config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("check"))
+        .stdout(predicate::str::contains("line-length"));
+
+    Ok(())
+}
+
+#[test]
+fn config_one_option() -> anyhow::Result<()> {
+    Command::cargo_bin(BIN_NAME)?
+        .arg("config")
+        .arg("check.line-length")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Default value: 100"))
+        .stdout(predicate::str::contains("Type: int"))
+        .stdout(predicate::str::contains("Example usage:"));
+
+    Ok(())
+}
+
+#[test]
+fn config_one_option_json() -> anyhow::Result<()> {
+    let output = Command::cargo_bin(BIN_NAME)?
+        .arg("config")
+        .arg("check.line-length")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["default"], "100");
+    assert_eq!(json["value_type"], "int");
+
+    Ok(())
+}
+
+#[test]
+fn config_nonexistent_option() -> anyhow::Result<()> {
+    Command::cargo_bin(BIN_NAME)?
+        .arg("config")
+        .arg("check.not-an-option")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown option"));
+
+    Ok(())
+}
@@ -98,3 +98,19 @@ fn explain_mixed_multiple() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn explain_preset() -> anyhow::Result<()> {
+    Command::cargo_bin(BIN_NAME)?
+        .arg("explain")
+        .arg("preset:legacy")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("C001"))
+        .stdout(predicate::str::contains(
+            "This rule is included in the following presets: strict, legacy, modern.",
+        ))
+        .stdout(predicate::str::contains("S061").count(0));
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+const BIN_NAME: &str = "fortitude";
+
+#[test]
+fn rule_all() -> anyhow::Result<()> {
+    Command::cargo_bin(BIN_NAME)?
+        .arg("rule")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("code"))
+        .stdout(predicate::str::contains("redirected from"))
+        .stdout(predicate::str::contains("C001"))
+        .stdout(predicate::str::contains("S061"));
+
+    Ok(())
+}
+
+#[test]
+fn rule_category() -> anyhow::Result<()> {
+    Command::cargo_bin(BIN_NAME)?
+        .arg("rule")
+        .arg("style")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("S061"))
+        .stdout(predicate::str::contains("C001").count(0));
+
+    Ok(())
+}
+
+#[test]
+fn rule_json() -> anyhow::Result<()> {
+    let output = Command::cargo_bin(BIN_NAME)?
+        .arg("rule")
+        .arg("C001")
+        .arg("--output-format=json")
+        .output()?;
+    assert!(output.status.success());
+
+    let rules: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        rules,
+        serde_json::json!([{
+            "code": "C001",
+            "name": "implicit-typing",
+            "category": "correctness",
+            "status": "stable",
+            "default": true,
+            "fix": "none",
+            "redirected_from": ["T001"],
+        }])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rule_nonexistent() -> anyhow::Result<()> {
+    Command::cargo_bin(BIN_NAME)?
+        .arg("rule")
+        .arg("X99999")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid"));
+
+    Ok(())
+}
@@ -10,6 +10,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::{bail, Result};
+use itertools::Itertools;
 use pretty_assertions::StrComparison;
 use regex::{Captures, Regex};
 use strum::IntoEnumIterator;
@@ -20,6 +21,7 @@ use fortitude::{
     options::Options,
     options_base::{OptionEntry, OptionsMetadata},
     registry::Rule,
+    rule_presets::Preset,
 };
 
 use crate::{
@@ -81,6 +83,19 @@ pub(crate) fn main(args: &Args) -> Result<()> {
                 output.push('\n');
             }
 
+            let presets = Preset::containing(rule);
+            if !presets.is_empty() {
+                output.push_str(&format!(
+                    "This rule is included in the following presets: {}.",
+                    presets
+                        .iter()
+                        .map(|preset| format!("`{preset}`"))
+                        .join(", ")
+                ));
+                output.push('\n');
+                output.push('\n');
+            }
+
             process_documentation(
                 explanation.trim(),
                 &mut output,
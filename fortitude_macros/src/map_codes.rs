@@ -454,14 +454,17 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
 
     let mut path_rule_variants = quote!();
     let mut path_rule_from_match_arms = quote!();
+    let mut path_rule_to_rule_match_arms = quote!();
     let mut path_rule_check_match_arms = quote!();
 
     let mut text_rule_variants = quote!();
     let mut text_rule_from_match_arms = quote!();
+    let mut text_rule_to_rule_match_arms = quote!();
     let mut text_rule_check_match_arms = quote!();
 
     let mut ast_rule_variants = quote!();
     let mut ast_rule_from_match_arms = quote!();
+    let mut ast_rule_to_rule_match_arms = quote!();
     let mut ast_rule_check_match_arms = quote!();
     let mut ast_rule_entrypoint_match_arms = quote!();
 
@@ -488,7 +491,7 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
 
         // Enable conversion from `DiagnosticKind` to `Rule`.
         from_impls_for_diagnostic_kind
-            .extend(quote! {#(#attrs)* stringify!(#name) => Rule::#name,});
+            .extend(quote! {#(#attrs)* stringify!(#name) => Some(Rule::#name),});
 
         // Next parts are for creating two enums for the different
         // rule `check` signatures. This basically allows us to a)
@@ -508,6 +511,10 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
                 #(#attrs)* Rule::#name => Ok(Self::#name),
             });
 
+            path_rule_to_rule_match_arms.extend(quote! {
+                #(#attrs)* Self::#name => Rule::#name,
+            });
+
             path_rule_check_match_arms.extend(quote! {
                 #(#attrs)* Self::#name => #path::check(settings, path),
             });
@@ -523,6 +530,10 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
                 #(#attrs)* Rule::#name => Ok(Self::#name),
             });
 
+            text_rule_to_rule_match_arms.extend(quote! {
+                #(#attrs)* Self::#name => Rule::#name,
+            });
+
             text_rule_check_match_arms.extend(quote! {
                 #(#attrs)* Self::#name => #path::check(settings, source),
             });
@@ -538,6 +549,10 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
                 #(#attrs)* Rule::#name => Ok(Self::#name),
             });
 
+            ast_rule_to_rule_match_arms.extend(quote! {
+                #(#attrs)* Self::#name => Rule::#name,
+            });
+
             ast_rule_check_match_arms.extend(quote! {
                 #(#attrs)* Self::#name => #path::check(settings, node, source),
             });
@@ -599,14 +614,21 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
                 match self { #rule_fixable_match_arms }
             }
 
+            /// Returns the rule for a violation with the given name, or `None`
+            /// if it was raised by a user-defined rule.
+            pub fn from_violation_name(name: &str) -> Option<Self> {
+                match name {
+                    #from_impls_for_diagnostic_kind
+                    _ => None,
+                }
+            }
+
         }
 
         impl AsRule for ruff_diagnostics::DiagnosticKind {
             fn rule(&self) -> Rule {
-                match self.name.as_str() {
-                    #from_impls_for_diagnostic_kind
-                    _ => unreachable!("invalid rule name: {}", self.name),
-                }
+                Rule::from_violation_name(&self.name)
+                    .unwrap_or_else(|| unreachable!("invalid rule name: {}", self.name))
             }
         }
 
@@ -642,6 +664,13 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
         }
 
         impl PathRuleEnum {
+            /// Returns the [`Rule`] this corresponds to.
+            pub fn rule(&self) -> Rule {
+                match self {
+                    #path_rule_to_rule_match_arms
+                }
+            }
+
             pub fn check(&self, settings: &Settings, path: &Path) -> Option<Diagnostic> {
                 match self {
                     #path_rule_check_match_arms
@@ -681,6 +710,13 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
         }
 
         impl TextRuleEnum {
+            /// Returns the [`Rule`] this corresponds to.
+            pub fn rule(&self) -> Rule {
+                match self {
+                    #text_rule_to_rule_match_arms
+                }
+            }
+
             pub fn check(&self, settings: &Settings, source: &SourceFile) -> Vec<Diagnostic> {
                 match self {
                     #text_rule_check_match_arms
@@ -720,6 +756,13 @@ fn register_rules<'a>(input: impl Iterator<Item = &'a RuleMeta>) -> TokenStream
         }
 
         impl AstRuleEnum {
+            /// Returns the [`Rule`] this corresponds to.
+            pub fn rule(&self) -> Rule {
+                match self {
+                    #ast_rule_to_rule_match_arms
+                }
+            }
+
             pub fn check(&self, settings: &Settings, node: &Node, source: &SourceFile) -> Option<Vec<Diagnostic>> {
                 match self {
                     #ast_rule_check_match_arms
@@ -0,0 +1,5 @@
+"""Python bindings for the Fortitude Fortran linter."""
+
+from ._native import Diagnostic, Edit, Fix, Location, check, check_source
+
+__all__ = ["Diagnostic", "Edit", "Fix", "Location", "check", "check_source"]
@@ -0,0 +1,39 @@
+from os import PathLike
+from typing import Literal, final
+
+@final
+class Location:
+    row: int
+    column: int
+
+@final
+class Edit:
+    content: str
+    location: Location
+    end_location: Location
+
+@final
+class Fix:
+    applicability: Literal["safe", "unsafe", "display-only"]
+    message: str | None
+    edits: list[Edit]
+
+@final
+class Diagnostic:
+    code: str
+    name: str
+    message: str
+    filename: str
+    location: Location
+    end_location: Location
+    fix: Fix | None
+
+def check(
+    paths: list[str | PathLike[str]],
+    config: str | PathLike[str] | None = None,
+) -> list[Diagnostic]: ...
+def check_source(
+    text: str,
+    path: str | PathLike[str] = "-",
+    config: str | PathLike[str] | None = None,
+) -> list[Diagnostic]: ...
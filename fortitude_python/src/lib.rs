@@ -0,0 +1,213 @@
+//! Python bindings for Fortitude, exposing the linter as a library rather
+//! than through the `fortitude` executable.
+use std::path::{Path, PathBuf};
+
+use fortitude::message::DiagnosticMessage;
+use fortitude::{lint_paths, lint_source, resolve_settings};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use ruff_diagnostics::{Applicability, Edit as RuffEdit};
+use ruff_source_file::{SourceCode, SourceLocation};
+use ruff_text_size::Ranged;
+
+/// A position in a file. Both the row and column start at 1.
+#[pyclass(frozen, get_all, module = "fortitude_native")]
+#[derive(Clone)]
+struct Location {
+    row: usize,
+    column: usize,
+}
+
+#[pymethods]
+impl Location {
+    fn __repr__(&self) -> String {
+        format!("Location(row={}, column={})", self.row, self.column)
+    }
+}
+
+impl From<SourceLocation> for Location {
+    fn from(location: SourceLocation) -> Self {
+        Self {
+            row: location.row.get(),
+            column: location.column.get(),
+        }
+    }
+}
+
+/// Replace the text between `location` and `end_location` with `content`.
+#[pyclass(frozen, get_all, module = "fortitude_native")]
+#[derive(Clone)]
+struct Edit {
+    content: String,
+    location: Location,
+    end_location: Location,
+}
+
+#[pymethods]
+impl Edit {
+    fn __repr__(&self) -> String {
+        format!(
+            "Edit(content={:?}, location={}, end_location={})",
+            self.content,
+            self.location.__repr__(),
+            self.end_location.__repr__()
+        )
+    }
+}
+
+impl Edit {
+    fn new(edit: &RuffEdit, source_code: &SourceCode) -> Self {
+        Self {
+            content: edit.content().unwrap_or_default().to_string(),
+            location: source_code.source_location(edit.start()).into(),
+            end_location: source_code.source_location(edit.end()).into(),
+        }
+    }
+}
+
+/// A suggested fix for a violation, made up of one or more edits.
+#[pyclass(frozen, get_all, module = "fortitude_native")]
+#[derive(Clone)]
+struct Fix {
+    /// One of `"safe"`, `"unsafe"` or `"display-only"`.
+    applicability: &'static str,
+    message: Option<String>,
+    edits: Vec<Edit>,
+}
+
+#[pymethods]
+impl Fix {
+    fn __repr__(&self) -> String {
+        format!(
+            "Fix(applicability={:?}, message={:?}, edits=[{}])",
+            self.applicability,
+            self.message,
+            self.edits
+                .iter()
+                .map(Edit::__repr__)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// A single rule violation.
+#[pyclass(frozen, get_all, module = "fortitude_native")]
+#[derive(Clone)]
+struct Diagnostic {
+    code: String,
+    name: String,
+    message: String,
+    filename: String,
+    location: Location,
+    end_location: Location,
+    fix: Option<Fix>,
+}
+
+#[pymethods]
+impl Diagnostic {
+    fn __repr__(&self) -> String {
+        format!(
+            "Diagnostic(code={:?}, name={:?}, filename={:?}, location={})",
+            self.code,
+            self.name,
+            self.filename,
+            self.location.__repr__()
+        )
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "{}:{}:{}: {} {}",
+            self.filename, self.location.row, self.location.column, self.code, self.message
+        )
+    }
+}
+
+impl From<&DiagnosticMessage> for Diagnostic {
+    fn from(message: &DiagnosticMessage) -> Self {
+        let source_code = message.source_file().to_source_code();
+
+        let fix = message.fix().map(|fix| Fix {
+            applicability: match fix.applicability() {
+                Applicability::Safe => "safe",
+                Applicability::Unsafe => "unsafe",
+                Applicability::DisplayOnly => "display-only",
+            },
+            message: message.suggestion().map(str::to_string),
+            edits: fix
+                .edits()
+                .iter()
+                .map(|edit| Edit::new(edit, &source_code))
+                .collect(),
+        });
+
+        Self {
+            code: message.code().to_string(),
+            name: message.rule_name().to_string(),
+            message: message.body().to_string(),
+            filename: message.filename().to_string(),
+            location: source_code.source_location(message.start()).into(),
+            end_location: source_code.source_location(message.end()).into(),
+            fix,
+        }
+    }
+}
+
+fn to_py_err(err: anyhow::Error) -> PyErr {
+    PyValueError::new_err(format!("{err:#}"))
+}
+
+/// Check files and directories, returning a list of diagnostics.
+///
+/// Settings are resolved as for `fortitude check`: unless `config` gives the
+/// path to a config file, one is discovered from the current directory.
+#[pyfunction]
+#[pyo3(signature = (paths, config = None))]
+fn check(
+    py: Python<'_>,
+    paths: Vec<PathBuf>,
+    config: Option<PathBuf>,
+) -> PyResult<Vec<Diagnostic>> {
+    let messages = py
+        .allow_threads(|| {
+            let settings = resolve_settings(&paths, config.as_deref())?;
+            lint_paths(&settings)
+        })
+        .map_err(to_py_err)?;
+    Ok(messages.iter().map(Diagnostic::from).collect())
+}
+
+/// Check Fortran source code, returning a list of diagnostics.
+///
+/// `path` is used in the diagnostics, and to apply settings such as
+/// `per-file-ignores`; it doesn't need to exist. Settings are resolved as for
+/// `check`.
+#[pyfunction]
+#[pyo3(signature = (text, path = PathBuf::from("-"), config = None))]
+fn check_source(
+    py: Python<'_>,
+    text: &str,
+    path: PathBuf,
+    config: Option<PathBuf>,
+) -> PyResult<Vec<Diagnostic>> {
+    let messages = py
+        .allow_threads(|| {
+            let settings = resolve_settings(&[Path::new(".")], config.as_deref())?;
+            lint_source(text, &path, &settings)
+        })
+        .map_err(to_py_err)?;
+    Ok(messages.iter().map(Diagnostic::from).collect())
+}
+
+#[pymodule]
+#[pyo3(name = "_native")]
+fn fortitude_native(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<Location>()?;
+    module.add_class::<Edit>()?;
+    module.add_class::<Fix>()?;
+    module.add_class::<Diagnostic>()?;
+    module.add_function(wrap_pyfunction!(check, module)?)?;
+    module.add_function(wrap_pyfunction!(check_source, module)?)?;
+    Ok(())
+}
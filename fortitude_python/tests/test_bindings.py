@@ -0,0 +1,70 @@
+from pathlib import Path
+
+import pytest
+
+import fortitude_native
+
+SOURCE = """\
+program test
+  real*8 :: x
+end program test
+"""
+
+
+@pytest.fixture
+def config(tmp_path: Path) -> Path:
+    config = tmp_path / "fortitude.toml"
+    config.write_text('[check]\nselect = ["C001", "PORT021"]\n')
+    return config
+
+
+def test_check_source(config: Path):
+    diagnostics = fortitude_native.check_source(SOURCE, "test.f90", config=config)
+
+    assert [d.code for d in diagnostics] == ["C001", "PORT021"]
+
+    implicit_typing = diagnostics[0]
+    assert implicit_typing.name == "implicit-typing"
+    assert implicit_typing.filename == "test.f90"
+    assert implicit_typing.location.row == 1
+    assert implicit_typing.location.column == 1
+    assert implicit_typing.fix is None
+
+    star_kind = diagnostics[1]
+    assert star_kind.location.row == 2
+    assert star_kind.location.column == 7
+    assert star_kind.end_location.row == 2
+    assert star_kind.end_location.column == 9
+    assert str(star_kind).startswith("test.f90:2:7: PORT021 ")
+
+
+def test_check_source_fix(config: Path):
+    diagnostics = fortitude_native.check_source(SOURCE, "test.f90", config=config)
+    fix = next(d.fix for d in diagnostics if d.code == "PORT021")
+
+    assert fix is not None
+    assert fix.applicability == "safe"
+    assert "real(real64)" in [edit.content for edit in fix.edits]
+
+
+def test_check_source_no_violations(config: Path):
+    source = "program test\n  implicit none\nend program test\n"
+    assert fortitude_native.check_source(source, "test.f90", config=config) == []
+
+
+def test_check(tmp_path: Path, config: Path):
+    (tmp_path / "test.f90").write_text(SOURCE)
+    (tmp_path / "notes.txt").write_text(SOURCE)
+
+    diagnostics = fortitude_native.check([tmp_path], config=config)
+
+    assert [d.code for d in diagnostics] == ["C001", "PORT021"]
+    assert all(Path(d.filename).name == "test.f90" for d in diagnostics)
+
+
+def test_invalid_config(tmp_path: Path):
+    config = tmp_path / "fortitude.toml"
+    config.write_text('[check]\nselect = ["NOT-A-RULE"]\n')
+
+    with pytest.raises(ValueError):
+        fortitude_native.check_source(SOURCE, "test.f90", config=config)
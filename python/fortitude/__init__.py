@@ -0,0 +1,24 @@
+"""The Fortitude Fortran linter.
+
+``check`` and ``check_source`` are provided by the optional native bindings,
+which can be installed with ``pip install fortitude-lint[bindings]``.
+"""
+
+_BINDINGS = ("Diagnostic", "Edit", "Fix", "Location", "check", "check_source")
+
+
+def __getattr__(name: str):
+    if name not in _BINDINGS:
+        msg = f"module {__name__!r} has no attribute {name!r}"
+        raise AttributeError(msg)
+
+    try:
+        import fortitude_native
+    except ImportError as err:
+        msg = (
+            f"fortitude.{name} requires the native bindings, which can be "
+            "installed with `pip install fortitude-lint[bindings]`"
+        )
+        raise ImportError(msg) from err
+
+    return getattr(fortitude_native, name)